@@ -2,7 +2,7 @@
 extern crate bencher;
 
 use bencher::Bencher;
-use zengine_ecs::{query::QueryIter, World};
+use zengine_ecs::{query::QueryIter, Entity, World};
 
 #[derive(Debug)]
 struct Component1 {
@@ -79,5 +79,80 @@ fn archetype_storage(bench: &mut Bencher) {
     });
 }
 
-benchmark_group!(benches, archetype_storage);
+fn spawn_entities(world: &mut World) -> Vec<Entity> {
+    (0..1000)
+        .map(|_| {
+            world.spawn((
+                Component1 { data: vec![1] },
+                Component2 { data: 7 },
+                Component4 { data: 2 },
+            ))
+        })
+        .collect()
+}
+
+fn component_insertion(bench: &mut Bencher) {
+    bench.iter(|| {
+        let mut world = World::default();
+        let entities: Vec<Entity> = (0..1000)
+            .map(|_| world.spawn(Component1 { data: vec![1] }))
+            .collect();
+
+        for entity in entities {
+            world.add_component(entity, Component2 { data: 7 });
+        }
+    });
+}
+
+fn component_removal(bench: &mut Bencher) {
+    bench.iter(|| {
+        let mut world = World::default();
+        let entities = spawn_entities(&mut world);
+
+        for entity in entities {
+            world.remove_component::<Component2>(entity);
+        }
+    });
+}
+
+fn component_migration(bench: &mut Bencher) {
+    let mut world = World::default();
+    let entities = spawn_entities(&mut world);
+
+    bench.iter(|| {
+        for entity in entities.iter() {
+            world.add_component(*entity, Component3 { data: 3 });
+        }
+
+        for entity in entities.iter() {
+            world.remove_component::<Component3>(*entity);
+        }
+    });
+}
+
+fn entity_spawn(bench: &mut Bencher) {
+    bench.iter(|| {
+        let mut world = World::default();
+        for _i in 0..1000 {
+            world.spawn((Component2 { data: 7 }, Component4 { data: 2 }));
+        }
+    });
+}
+
+fn entity_spawn_batch(bench: &mut Bencher) {
+    bench.iter(|| {
+        let mut world = World::default();
+        world.spawn_batch((0..1000).map(|_| (Component2 { data: 7 }, Component4 { data: 2 })));
+    });
+}
+
+benchmark_group!(
+    benches,
+    archetype_storage,
+    component_insertion,
+    component_removal,
+    component_migration,
+    entity_spawn,
+    entity_spawn_batch
+);
 benchmark_main!(benches);
@@ -0,0 +1,267 @@
+use std::marker::PhantomData;
+
+use zengine_ecs::{
+    system::{Commands, EventPublisher, Local, Res, ResMut},
+    Resource,
+};
+
+use crate::{AssetError, AssetManager, HandleId, LoadState};
+
+/// A [Resource] made of asset handles that are loaded together
+///
+/// It can be derived for a struct where each field has an `asset` attribute
+/// with the `path` of an asset, or the `folder` of the assets for a `Vec` of handles.
+/// The fields without the attribute are created with their default value.
+///
+/// The collection is created and loaded during the [Startup Stage](zengine_engine::Stage::Startup)
+/// by [AssetExtension::add_asset_collection](crate::AssetExtension::add_asset_collection),
+/// then an [AssetCollectionEvent] is published when all its assets are ready.
+/// The collection is not created if it can't be loaded, like when one of its folders
+/// doesn't exist, and a failed [AssetCollectionEvent] is published instead.
+///
+/// # Example
+/// ```no_run
+/// use zengine_asset::{AssetExtension, AssetModule, Handle};
+/// use zengine_engine::Engine;
+/// use zengine_macro::{Asset, AssetCollection, Resource};
+///
+/// # #[derive(Asset, Debug)]
+/// # struct Image;
+/// # #[derive(Asset, Debug)]
+/// # struct Audio;
+/// #[derive(AssetCollection, Resource, Debug)]
+/// struct GameAssets {
+///     #[asset(path = "player.png")]
+///     player: Handle<Image>,
+///     #[asset(folder = "sounds")]
+///     sounds: Vec<Handle<Audio>>,
+/// }
+///
+/// Engine::default()
+///     .add_module(AssetModule::new("assets"))
+///     .add_asset_collection::<GameAssets>();
+/// ```
+pub trait AssetCollection: Resource + Sized {
+    /// Starts loading all the assets of the collection
+    ///
+    /// Returns an error if the assets to load can't be found, like the files of a missing folder
+    fn load(asset_manager: &mut AssetManager) -> Result<Self, AssetError>;
+
+    /// Returns the ids of all the assets of the collection
+    fn handle_ids(&self) -> Vec<HandleId>;
+}
+
+/// Event published once when all the assets of an [AssetCollection]
+/// and their dependencies have been loaded, or when one of them failed to load
+/// or the collection itself could not be loaded
+#[derive(Debug)]
+pub struct AssetCollectionEvent<C: AssetCollection> {
+    /// Either [LoadState::Loaded] or [LoadState::Failed]
+    pub state: LoadState,
+    collection: PhantomData<fn() -> C>,
+}
+
+/// Returns the [LoadState] of a collection, it's failed as soon as one of its assets failed
+fn collection_load_state<C: AssetCollection>(
+    collection: &C,
+    asset_manager: &AssetManager,
+) -> LoadState {
+    let mut state = LoadState::Loaded;
+    for id in collection.handle_ids() {
+        match asset_manager.get_recursive_load_state(id) {
+            LoadState::Failed => return LoadState::Failed,
+            LoadState::Loaded => {}
+            LoadState::NotLoaded | LoadState::Loading => state = LoadState::Loading,
+        }
+    }
+
+    state
+}
+
+pub(crate) fn load_asset_collection<C: AssetCollection>(
+    mut asset_manager: ResMut<AssetManager>,
+    mut commands: Commands,
+    mut collection_event: EventPublisher<AssetCollectionEvent<C>>,
+) {
+    match C::load(&mut asset_manager) {
+        Ok(collection) => commands.create_resource(collection),
+        Err(error) => {
+            log::error!(
+                "Could not load the asset collection {}: {}",
+                std::any::type_name::<C>(),
+                error
+            );
+            collection_event.publish(AssetCollectionEvent {
+                state: LoadState::Failed,
+                collection: PhantomData,
+            });
+        }
+    }
+}
+
+pub(crate) fn check_asset_collection<C: AssetCollection>(
+    published: Local<bool>,
+    collection: Option<Res<C>>,
+    asset_manager: Res<AssetManager>,
+    mut collection_event: EventPublisher<AssetCollectionEvent<C>>,
+) {
+    let Some(collection) = collection.filter(|_| !*published) else {
+        return;
+    };
+
+    let state = collection_load_state(&*collection, &asset_manager);
+    if matches!(state, LoadState::Loaded | LoadState::Failed) {
+        *published = true;
+        collection_event.publish(AssetCollectionEvent {
+            state,
+            collection: PhantomData,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use zengine_engine::Engine;
+    use zengine_macro::Resource;
+
+    use crate::{
+        io::MemoryAssetIo,
+        test_utils::{level_engine, Level, LevelLoader},
+        AssetCollection, AssetCollectionEvent, AssetError, AssetExtension, AssetManager,
+        AssetModule, Handle, HandleId, LoadState,
+    };
+
+    #[derive(Resource, Debug)]
+    struct Levels {
+        first: Handle<Level>,
+        all: Vec<Handle<Level>>,
+    }
+    impl AssetCollection for Levels {
+        fn load(asset_manager: &mut AssetManager) -> Result<Self, AssetError> {
+            Ok(Self {
+                first: asset_manager.load("levels/1.level"),
+                all: asset_manager.load_folder("levels")?,
+            })
+        }
+
+        fn handle_ids(&self) -> Vec<HandleId> {
+            std::iter::once(&self.first)
+                .chain(&self.all)
+                .map(Handle::get_id)
+                .collect()
+        }
+    }
+
+    #[derive(Resource, Debug)]
+    struct MissingLevels {
+        levels: Vec<Handle<Level>>,
+    }
+    impl AssetCollection for MissingLevels {
+        fn load(asset_manager: &mut AssetManager) -> Result<Self, AssetError> {
+            Ok(Self {
+                levels: vec![
+                    asset_manager.load("levels/1.level"),
+                    asset_manager.load("levels/missing.level"),
+                ],
+            })
+        }
+
+        fn handle_ids(&self) -> Vec<HandleId> {
+            self.levels.iter().map(Handle::get_id).collect()
+        }
+    }
+
+    #[derive(Resource, Debug)]
+    struct MissingFolder {
+        levels: Vec<Handle<Level>>,
+    }
+    impl AssetCollection for MissingFolder {
+        fn load(asset_manager: &mut AssetManager) -> Result<Self, AssetError> {
+            Ok(Self {
+                levels: asset_manager.load_folder("missing")?,
+            })
+        }
+
+        fn handle_ids(&self) -> Vec<HandleId> {
+            self.levels.iter().map(Handle::get_id).collect()
+        }
+    }
+
+    fn setup() -> Engine {
+        level_engine(
+            MemoryAssetIo::default()
+                .with_asset("levels/1.level", [1].as_slice())
+                .with_asset("levels/2.level", [2].as_slice()),
+        )
+    }
+
+    fn wait_for_event<C: AssetCollection>(engine: &mut Engine) -> LoadState {
+        engine.startup();
+        for _ in 0..200 {
+            engine.update();
+            let event_handler = engine
+                .world
+                .get_event_handler::<AssetCollectionEvent<C>>()
+                .unwrap();
+            if let Some(event) = event_handler.read_last() {
+                return event.state;
+            }
+            drop(event_handler);
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        panic!("The asset collection event has not been published");
+    }
+
+    #[test]
+    fn collection_loaded() {
+        let mut engine = setup();
+        engine.add_asset_collection::<Levels>();
+
+        assert_eq!(wait_for_event::<Levels>(&mut engine), LoadState::Loaded);
+        assert_eq!(engine.world.get_resource::<Levels>().unwrap().all.len(), 2);
+    }
+
+    #[test]
+    fn collection_loaded_from_module_folder() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("levels")).unwrap();
+        std::fs::write(dir.path().join("levels/1.level"), [1]).unwrap();
+        std::fs::write(dir.path().join("levels/2.level"), [2]).unwrap();
+
+        let mut engine = Engine::default();
+        engine
+            .add_module(AssetModule::new(dir.path()))
+            .add_asset::<Level>()
+            .add_asset_loader(LevelLoader)
+            .add_asset_collection::<Levels>();
+
+        assert_eq!(wait_for_event::<Levels>(&mut engine), LoadState::Loaded);
+        assert_eq!(engine.world.get_resource::<Levels>().unwrap().all.len(), 2);
+    }
+
+    #[test]
+    fn collection_failed() {
+        let mut engine = setup();
+        engine.add_asset_collection::<MissingLevels>();
+
+        assert_eq!(
+            wait_for_event::<MissingLevels>(&mut engine),
+            LoadState::Failed
+        );
+    }
+
+    #[test]
+    fn collection_folder_missing() {
+        let mut engine = setup();
+        engine.add_asset_collection::<MissingFolder>();
+
+        assert_eq!(
+            wait_for_event::<MissingFolder>(&mut engine),
+            LoadState::Failed
+        );
+        assert!(engine.world.get_resource::<MissingFolder>().is_none());
+    }
+}
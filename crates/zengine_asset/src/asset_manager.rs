@@ -1,20 +1,32 @@
 use crossbeam_channel::{Receiver, Sender, TryRecvError};
 use downcast_rs::{impl_downcast, Downcast};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::de::DeserializeOwned;
 use std::sync::{Arc, RwLock};
-use std::{any::TypeId, path::Path};
+use std::{
+    any::{Any, TypeId},
+    path::{Path, PathBuf},
+};
 use zengine_ecs::system::{EventPublisher, Res, ResMut};
-use zengine_engine::log::debug;
+use zengine_engine::{
+    log::{debug, error},
+    Task, TaskPool, TaskPriority,
+};
 use zengine_macro::Resource;
 
 use crate::assets::Assets;
-use crate::handle::{HandleId, HandleRef, HandleRefChannel};
-use crate::io::AssetIo;
-use crate::AssetEvent;
+use crate::handle::{HandleId, HandleRef, HandleRefChannel, HandleUntyped};
+use crate::io::{AssetIo, MemoryAssetIo};
 use crate::{
     assets::{Asset, AssetPath},
     handle::Handle,
 };
+use crate::{AssetError, AssetEvent, LoaderError};
+
+pub(crate) const EMBEDDED_SOURCE: &str = "embedded";
+const META_EXTENSION: &str = "meta";
+
+type LoaderSettings = Arc<dyn Any + Send + Sync>;
 
 /// A context where an [`Asset`] is processed
 ///
@@ -23,19 +35,99 @@ use crate::{
 /// extension of the asset's path.
 pub struct LoaderContext<'a> {
     asset: Option<Box<dyn Asset>>,
-    path: &'a Path,
+    asset_path: &'a AssetPath,
+    handle_ref_sender: &'a Sender<HandleRef>,
+    labeled_assets: Vec<(String, Box<dyn Asset>)>,
+    dependencies: Vec<(AssetPath, HandleUntyped)>,
+    settings: Option<&'a (dyn Any + Send + Sync)>,
+    meta: Option<&'a [u8]>,
 }
 
 impl<'a> LoaderContext<'a> {
     /// Gets the source path for this load context.
     pub fn path(&self) -> &Path {
-        self.path
+        &self.asset_path.path
+    }
+
+    /// Returns the settings of the loader for this asset
+    ///
+    /// The settings are the ones passed to [AssetManager::load_with_settings] or,
+    /// if the asset has been loaded without them, the ones read from the `.meta` file
+    /// next to the asset file, like `player.png.meta` for `player.png`.
+    /// The meta file contains the settings written in [RON](https://github.com/ron-rs/ron).
+    /// Without both of them the default settings are returned.
+    ///
+    /// # Example
+    /// ```ignore
+    /// #[derive(Debug, Default, Clone, Deserialize)]
+    /// #[serde(default)]
+    /// struct FontSettings {
+    ///     size: f32,
+    /// }
+    ///
+    /// fn load(&self, data: Vec<u8>, context: &mut LoaderContext) -> Result<(), LoaderError> {
+    ///     let settings: FontSettings = context.settings()?;
+    ///     ...
+    /// }
+    /// ```
+    pub fn settings<S>(&self) -> Result<S, LoaderError>
+    where
+        S: DeserializeOwned + Default + Clone + Send + Sync + 'static,
+    {
+        if let Some(settings) = self.settings {
+            return settings.downcast_ref::<S>().cloned().ok_or_else(|| {
+                format!(
+                    "Wrong loader settings, expected {}",
+                    std::any::type_name::<S>()
+                )
+                .into()
+            });
+        }
+
+        match self.meta {
+            Some(meta) => Ok(ron::de::from_bytes(meta)?),
+            None => Ok(S::default()),
+        }
     }
 
     /// Sets the asset loaded from the asset raw data.
     pub fn set_asset<T: Asset>(&mut self, asset: T) {
         self.asset.replace(Box::new(asset));
     }
+
+    /// Adds one of the assets contained in the asset raw data, like the atlas of a sprite sheet.
+    /// It replaces the asset previously added with the same label
+    ///
+    /// The asset can be loaded using its label after a `#`, like `"sheet.json#atlas"`.
+    /// All the assets of the file are kept loaded as long as the requested one is,
+    /// so the returned handle is a weak one that can be stored in the other assets of the file
+    /// without keeping them alive forever.
+    pub fn add_labeled_asset<T: Asset>(&mut self, label: &str, asset: T) -> Handle<T> {
+        let asset_path = self.asset_path.clone().with_label(Some(label.to_owned()));
+        let handle_id = HandleId::new_from_path::<T>(&asset_path);
+
+        self.labeled_assets.retain(|(l, _)| l != label);
+        self.labeled_assets
+            .push((label.to_owned(), Box::new(asset)));
+
+        Handle::weak(handle_id)
+    }
+
+    /// Loads an [Asset] this asset depends on, like the images used by a tilemap
+    ///
+    /// The path is relative to the asset base path, like the one passed to [AssetManager::load].
+    /// The dependency is loaded after this asset and it's kept loaded as long as this asset is.
+    /// See [AssetManager::get_recursive_load_state]
+    pub fn load<T: Asset, P: Into<AssetPath>>(&mut self, file_path: P) -> Handle<T> {
+        let asset_path = file_path.into();
+        let handle_id = HandleId::new_from_path::<T>(&asset_path);
+        let handle = Handle::strong(handle_id, self.handle_ref_sender.clone());
+
+        self.dependencies
+            .push((asset_path, handle.clone().into_untyped()));
+
+        handle
+    }
 }
 
 /// A loader for an asset
@@ -45,11 +137,13 @@ impl<'a> LoaderContext<'a> {
 pub trait AssetLoader: Send + Sync + std::fmt::Debug + 'static {
     /// Process the asset creating an instance of the `Asset` from raw data
     ///
+    /// When an error is returned the asset fails to load and an
+    /// [AssetEvent::Failed] is published
+    ///
     /// # Example
     /// ```ignore
-    /// fn load(&self, data: Vec<u8>, context: &mut LoaderContext) {
-    ///     let img =
-    ///         image::load_from_memory(&data).unwrap_or_else(|e| panic!("Could not load image {}", e));
+    /// fn load(&self, data: Vec<u8>, context: &mut LoaderContext) -> Result<(), LoaderError> {
+    ///     let img = image::load_from_memory(&data)?;
     ///
     ///     let (width, height) = img.dimensions();
     ///
@@ -62,10 +156,12 @@ pub trait AssetLoader: Send + Sync + std::fmt::Debug + 'static {
     ///         width,
     ///         height,
     ///         data: img.into_raw(),
-    ///     })
+    ///     });
+    ///
+    ///     Ok(())
     /// }
     /// ```
-    fn load(&self, data: Vec<u8>, context: &mut LoaderContext);
+    fn load(&self, data: Vec<u8>, context: &mut LoaderContext) -> Result<(), LoaderError>;
 
     /// Return a list of extensions supported by this asset loader. Without the `dot`
     ///
@@ -78,13 +174,24 @@ pub trait AssetLoader: Send + Sync + std::fmt::Debug + 'static {
     fn extension(&self) -> &[&str];
 }
 
+struct LoadedAsset {
+    asset: Option<Box<dyn Asset>>,
+    labeled_assets: Vec<(String, Box<dyn Asset>)>,
+    dependencies: Vec<(AssetPath, HandleUntyped)>,
+}
+
 struct AssetCreateCommand<T> {
     pub id: HandleId,
     pub asset: T,
+    pub dependencies: Vec<(AssetPath, HandleUntyped)>,
+    pub file_assets: Vec<HandleUntyped>,
+    pub reloaded: bool,
+    pub task: Task,
 }
 
 enum AssetCommand<T> {
     Create(AssetCreateCommand<T>),
+    Fail(HandleId, AssetError, Task),
     Destroy(HandleId),
 }
 
@@ -102,22 +209,42 @@ impl<T> Default for AssetCommandChannel<T> {
 }
 
 trait AnyAssetCommandChannel: Downcast + Sync + Send + std::fmt::Debug + 'static {
-    fn create(&self, id: HandleId, asset: Box<dyn Asset>);
+    fn create(&self, path: &Path, command: AssetCreateCommand<Box<dyn Asset>>);
+
+    fn fail(&self, id: HandleId, error: AssetError, task: Task);
 
     fn destroy(&self, id: HandleId);
 }
 impl_downcast!(AnyAssetCommandChannel);
 
 impl<T: Asset> AnyAssetCommandChannel for AssetCommandChannel<T> {
-    fn create(&self, id: HandleId, asset: Box<dyn Asset>) {
-        let asset = asset.downcast::<T>().unwrap_or_else(|_| {
-            panic!("Failed to downcast asset to {}", std::any::type_name::<T>())
-        });
+    fn create(&self, path: &Path, command: AssetCreateCommand<Box<dyn Asset>>) {
+        match command.asset.downcast::<T>() {
+            Ok(asset) => self
+                .sender
+                .send(AssetCommand::Create(AssetCreateCommand {
+                    id: command.id,
+                    asset: *asset,
+                    dependencies: command.dependencies,
+                    file_assets: command.file_assets,
+                    reloaded: command.reloaded,
+                    task: command.task,
+                }))
+                .unwrap(),
+            Err(_) => self.fail(
+                command.id,
+                AssetError::WrongType {
+                    path: path.to_path_buf(),
+                    expected: std::any::type_name::<T>(),
+                },
+                command.task,
+            ),
+        }
+    }
+
+    fn fail(&self, id: HandleId, error: AssetError, task: Task) {
         self.sender
-            .send(AssetCommand::Create(AssetCreateCommand {
-                id,
-                asset: *asset,
-            }))
+            .send(AssetCommand::Fail(id, error, task))
             .unwrap();
     }
 
@@ -126,6 +253,19 @@ impl<T: Asset> AnyAssetCommandChannel for AssetCommandChannel<T> {
     }
 }
 
+/// The load state of an asset
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadState {
+    /// The asset has not been requested or it has been unloaded
+    NotLoaded,
+    /// The asset is being loaded in the background
+    Loading,
+    /// The asset is available in the [Assets] storage
+    Loaded,
+    /// The asset failed to load, an [AssetEvent::Failed] has been published
+    Failed,
+}
+
 /// A [Resource](zengine_ecs::Resource) that loads assets from the filesystem in the background
 ///
 /// The asset manager keeps track of the load state
@@ -158,7 +298,17 @@ pub struct AssetManager {
     asset_channels: Arc<RwLock<FxHashMap<TypeId, Box<dyn AnyAssetCommandChannel>>>>,
     asset_handle_ref_channel: HandleRefChannel,
     asset_handle_ref_count: FxHashMap<HandleId, usize>,
+    load_states: RwLock<FxHashMap<HandleId, LoadState>>,
+    asset_paths: RwLock<FxHashMap<PathBuf, Vec<(HandleId, AssetPath)>>>,
+    dependencies: RwLock<FxHashMap<HandleId, Vec<HandleUntyped>>>,
+    file_assets: RwLock<FxHashMap<HandleId, Vec<HandleId>>>,
+    changed_assets: Option<Receiver<PathBuf>>,
+    loading_tasks: RwLock<FxHashMap<HandleId, Task>>,
+    task_pool: TaskPool,
     asset_io: Arc<dyn AssetIo>,
+    asset_sources: FxHashMap<String, Arc<dyn AssetIo>>,
+    embedded_assets: MemoryAssetIo,
+    loader_settings: RwLock<FxHashMap<HandleId, LoaderSettings>>,
 }
 
 impl Default for AssetManager {
@@ -170,7 +320,17 @@ impl Default for AssetManager {
             asset_channels: Arc::new(RwLock::new(FxHashMap::default())),
             asset_handle_ref_channel: HandleRefChannel::default(),
             asset_handle_ref_count: FxHashMap::default(),
+            load_states: RwLock::default(),
+            asset_paths: RwLock::default(),
+            dependencies: RwLock::default(),
+            file_assets: RwLock::default(),
+            changed_assets: None,
+            loading_tasks: RwLock::default(),
+            task_pool: TaskPool::default(),
             asset_io: Arc::new(crate::io::WasmAssetIo::default()),
+            asset_sources: FxHashMap::default(),
+            embedded_assets: MemoryAssetIo::default(),
+            loader_settings: RwLock::default(),
         };
 
         #[cfg(target_os = "android")]
@@ -180,7 +340,17 @@ impl Default for AssetManager {
             asset_channels: Arc::new(RwLock::new(FxHashMap::default())),
             asset_handle_ref_channel: HandleRefChannel::default(),
             asset_handle_ref_count: FxHashMap::default(),
+            load_states: RwLock::default(),
+            asset_paths: RwLock::default(),
+            dependencies: RwLock::default(),
+            file_assets: RwLock::default(),
+            changed_assets: None,
+            loading_tasks: RwLock::default(),
+            task_pool: TaskPool::default(),
             asset_io: Arc::new(crate::io::AndroidAssetIo::default()),
+            asset_sources: FxHashMap::default(),
+            embedded_assets: MemoryAssetIo::default(),
+            loader_settings: RwLock::default(),
         };
 
         #[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
@@ -190,7 +360,17 @@ impl Default for AssetManager {
             asset_channels: Arc::new(RwLock::new(FxHashMap::default())),
             asset_handle_ref_channel: HandleRefChannel::default(),
             asset_handle_ref_count: FxHashMap::default(),
+            load_states: RwLock::default(),
+            asset_paths: RwLock::default(),
+            dependencies: RwLock::default(),
+            file_assets: RwLock::default(),
+            changed_assets: None,
+            loading_tasks: RwLock::default(),
+            task_pool: TaskPool::default(),
             asset_io: Arc::new(crate::io::FileAssetIo::default()),
+            asset_sources: FxHashMap::default(),
+            embedded_assets: MemoryAssetIo::default(),
+            loader_settings: RwLock::default(),
         };
     }
 }
@@ -204,7 +384,76 @@ impl AssetManager {
             asset_channels: Arc::new(RwLock::new(FxHashMap::default())),
             asset_handle_ref_channel: HandleRefChannel::default(),
             asset_handle_ref_count: FxHashMap::default(),
+            load_states: RwLock::default(),
+            asset_paths: RwLock::default(),
+            dependencies: RwLock::default(),
+            file_assets: RwLock::default(),
+            changed_assets: None,
+            loading_tasks: RwLock::default(),
+            task_pool: TaskPool::default(),
             asset_io: Arc::new(asset_io),
+            asset_sources: FxHashMap::default(),
+            embedded_assets: MemoryAssetIo::default(),
+            loader_settings: RwLock::default(),
+        }
+    }
+
+    pub(crate) fn set_asset_io<T: AssetIo>(&mut self, asset_io: T) {
+        self.asset_io = Arc::new(asset_io);
+    }
+
+    pub(crate) fn set_task_pool(&mut self, task_pool: TaskPool) {
+        self.task_pool = task_pool;
+    }
+
+    /// Adds a named asset source
+    ///
+    /// The assets are loaded from the source using a path that starts with
+    /// the name of the source followed by `://`, like `"my_source://logo.png"`.
+    /// It replaces the source previously added with the same name.
+    ///
+    /// # Panics
+    /// Panics if the name is `embedded`, it's reserved to the assets
+    /// added with [AssetManager::add_embedded_asset]
+    pub fn add_asset_source<T: AssetIo>(&mut self, name: &str, asset_io: T) {
+        self.add_asset_source_arc(name, Arc::new(asset_io));
+    }
+
+    pub(crate) fn add_asset_source_arc(&mut self, name: &str, asset_io: Arc<dyn AssetIo>) {
+        assert!(
+            name != EMBEDDED_SOURCE,
+            "The asset source name \"{}\" is reserved to the embedded assets",
+            EMBEDDED_SOURCE
+        );
+        self.asset_sources.insert(name.to_owned(), asset_io);
+    }
+
+    /// Adds an asset embedded in the executable that can be loaded
+    /// with the `embedded://` prefix, like `"embedded://logo.png"`.
+    /// See [embedded_asset](crate::embedded_asset)
+    ///
+    /// A path with the `embedded://` prefix that doesn't match an embedded asset or folder
+    /// is loaded from the default source, so the embedded assets can override the asset files
+    pub fn add_embedded_asset<P: AsRef<Path>>(&self, path: P, data: &'static [u8]) {
+        self.embedded_assets.insert(path, data);
+    }
+
+    fn is_embedded(&self, path: &Path) -> bool {
+        self.embedded_assets.contains(path)
+            || self
+                .embedded_assets
+                .read_directory(path)
+                .is_ok_and(|entries| !entries.is_empty())
+    }
+
+    fn find_asset_io(&self, asset_path: &AssetPath) -> Option<Arc<dyn AssetIo>> {
+        match asset_path.source.as_deref() {
+            None => Some(self.asset_io.clone()),
+            Some(EMBEDDED_SOURCE) if self.is_embedded(&asset_path.path) => {
+                Some(Arc::new(self.embedded_assets.clone()))
+            }
+            Some(EMBEDDED_SOURCE) => Some(self.asset_io.clone()),
+            Some(source) => self.asset_sources.get(source).cloned(),
         }
     }
 
@@ -218,37 +467,300 @@ impl AssetManager {
     ///
     /// The asset is loaded asynchronously, and will generally not be available by the time
     /// this calls returns. The engine will generate an [AssetEvent::Loaded] when the asset
-    /// will be available.
+    /// will be available or an [AssetEvent::Failed] if the asset can't be loaded.
     ///
     /// The asset will always fail to load if the provided path doesn't contain an extension.
+    ///
+    /// A path like `"sheet.json#atlas"` loads the asset labeled `atlas` produced by the loader
+    /// of `sheet.json`. See [LoaderContext::add_labeled_asset]
+    ///
+    /// The asset is loaded by the [TaskPool] and the load is canceled
+    /// if all the strong handles are dropped before the asset is available.
     pub fn load<T: Asset, P: Into<AssetPath>>(&mut self, file_path: P) -> Handle<T> {
+        self.load_with_priority(file_path, TaskPriority::Normal)
+    }
+
+    /// Asynchronous load an [Asset] like [AssetManager::load]
+    /// using the given priority for the load task
+    pub fn load_with_priority<T: Asset, P: Into<AssetPath>>(
+        &mut self,
+        file_path: P,
+        priority: TaskPriority,
+    ) -> Handle<T> {
         let asset_path = file_path.into();
         let handle_id = HandleId::new_from_path::<T>(&asset_path);
+        let handle = Handle::strong(handle_id, self.asset_handle_ref_channel.sender.clone());
 
-        let loader = self
-            .find_loader(&asset_path.extension)
-            .expect("Asset loader not found");
+        self.start_load(handle_id, asset_path, priority);
 
-        let asset_channels = self.asset_channels.clone();
+        handle
+    }
 
-        let asset_io = self.asset_io.clone();
-        crate::io_task::spawn(async move {
-            let data = asset_io.load(&asset_path.path).await;
+    /// Asynchronous load all the assets in a folder and in its subfolders
+    /// that have an [AssetLoader] registered for their extension
+    ///
+    /// The folder path is relative like the one passed to [AssetManager::load],
+    /// the returned handles are sorted by the asset path.
+    /// All the assets are loaded as `T`, so the ones of a different type fail to load.
+    ///
+    /// Returns an error if the files of the folder can't be listed,
+    /// which is not supported on the web. See [AssetIo::read_directory]
+    pub fn load_folder<T: Asset, P: Into<AssetPath>>(
+        &mut self,
+        folder_path: P,
+    ) -> Result<Vec<Handle<T>>, AssetError> {
+        let folder_path = folder_path.into();
+        let asset_io = self
+            .find_asset_io(&folder_path)
+            .ok_or_else(|| AssetError::MissingSource(folder_path.full_path()))?;
 
-            let mut context = LoaderContext {
-                asset: None,
-                path: asset_path.path.as_path(),
-            };
+        let mut paths = asset_io.read_directory(&folder_path.path)?;
+        paths.sort();
+
+        let asset_paths: Vec<AssetPath> = paths
+            .iter()
+            .map(|path| {
+                let mut asset_path = AssetPath::from(path.as_path());
+                asset_path.source = folder_path.source.clone();
+                asset_path
+            })
+            .filter(|asset_path| self.find_loader(&asset_path.extension).is_some())
+            .collect();
+
+        Ok(asset_paths
+            .into_iter()
+            .map(|asset_path| self.load(asset_path))
+            .collect())
+    }
+
+    /// Asynchronous load an [Asset] like [AssetManager::load]
+    /// passing the given settings to its [AssetLoader]
+    ///
+    /// The settings replace the ones in the `.meta` file of the asset
+    /// and they are used also when the asset is reloaded.
+    /// See [LoaderContext::settings]
+    pub fn load_with_settings<T, S, P>(&mut self, file_path: P, settings: S) -> Handle<T>
+    where
+        T: Asset,
+        S: Send + Sync + 'static,
+        P: Into<AssetPath>,
+    {
+        let asset_path = file_path.into();
+        let handle_id = HandleId::new_from_path::<T>(&asset_path);
+        let handle = Handle::strong(handle_id, self.asset_handle_ref_channel.sender.clone());
+
+        self.loader_settings
+            .write()
+            .unwrap()
+            .insert(handle_id, Arc::new(settings));
+        self.start_load(handle_id, asset_path, TaskPriority::Normal);
 
-            loader.load(data, &mut context);
+        handle
+    }
+
+    fn start_load(&self, handle_id: HandleId, asset_path: AssetPath, priority: TaskPriority) {
+        // only the default source is watched for changes.
+        // The path is kept also after the asset is unloaded to reload it when it changes,
+        // see `watch_for_changes`
+        if asset_path.source.is_none() {
+            let mut asset_paths = self.asset_paths.write().unwrap();
+            let ids = asset_paths.entry(asset_path.path.clone()).or_default();
+            if !ids.iter().any(|(id, _)| *id == handle_id) {
+                ids.push((handle_id, asset_path.clone()));
+            }
+        }
+
+        self.set_load_state(handle_id, LoadState::Loading);
+        self.spawn_load(handle_id, asset_path, priority, false);
+    }
+
+    /// Starts watching the asset files for changes
+    ///
+    /// When the file of an asset that has been loaded changes, the asset is loaded again
+    /// and replaced in the [Assets](crate::Assets) storage under the same [HandleId].
+    /// Then an [AssetEvent::Modified] is published.
+    /// The asset is reloaded also when its `.meta` file changes
+    ///
+    /// Also the assets that have been unloaded are reloaded: the [AssetEvent::Modified]
+    /// is published and then the asset is unloaded again if nobody is using it.
+    /// This way an asset that has been converted into another one,
+    /// like an image uploaded to the GPU by a texture, can be converted again.
+    ///
+    /// The [FileAssetIo](crate::io::FileAssetIo) supports it
+    /// only with the `hot_reload` feature enabled
+    pub fn watch_for_changes(&mut self) {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        self.asset_io.watch_for_changes(sender);
+        self.changed_assets = Some(receiver);
+    }
+
+    fn reload_changed_assets(&self) {
+        let Some(changed_assets) = &self.changed_assets else {
+            return;
+        };
 
+        // an editor could write the same file multiple times while saving it
+        let changed_paths: FxHashSet<PathBuf> = changed_assets.try_iter().collect();
+        let asset_paths = self.asset_paths.read().unwrap();
+        for path in changed_paths {
+            let path = if path.extension().is_some_and(|e| e == META_EXTENSION) {
+                path.with_extension("")
+            } else {
+                path
+            };
+            for (id, asset_path) in asset_paths.get(&path).into_iter().flatten() {
+                debug!("Reload asset {:?}. Asset id: {:?}", path, id);
+                self.spawn_load(*id, asset_path.clone(), TaskPriority::Normal, true);
+            }
+        }
+    }
+
+    fn spawn_load(
+        &self,
+        handle_id: HandleId,
+        asset_path: AssetPath,
+        priority: TaskPriority,
+        reloaded: bool,
+    ) {
+        let asset_channels = self.asset_channels.clone();
+        let handle_ref_sender = self.asset_handle_ref_channel.sender.clone();
+        let send_result = move |result: Result<LoadedAsset, AssetError>,
+                                asset_path: &AssetPath,
+                                task: Task| {
             let asset_channels = asset_channels.read().unwrap();
-            let asset_channel = asset_channels.get(&handle_id.get_type()).unwrap();
+            let asset_channel = asset_channels
+                .get(&handle_id.get_type())
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Asset type of {:?} has not been registered",
+                        asset_path.full_path()
+                    )
+                });
 
-            asset_channel.create(handle_id, context.asset.unwrap());
-        });
+            match result {
+                Ok(loaded_asset) => send_loaded_asset(
+                    &asset_channels,
+                    handle_id,
+                    asset_path,
+                    loaded_asset,
+                    &handle_ref_sender,
+                    reloaded,
+                    task,
+                ),
+                Err(error) => asset_channel.fail(handle_id, error, task),
+            }
+        };
+
+        let Some(asset_io) = self.find_asset_io(&asset_path) else {
+            send_result(
+                Err(AssetError::MissingSource(asset_path.full_path())),
+                &asset_path,
+                Task::default(),
+            );
+            return;
+        };
 
-        Handle::strong(handle_id, self.asset_handle_ref_channel.sender.clone())
+        let Some(loader) = self.find_loader(&asset_path.extension) else {
+            send_result(
+                Err(AssetError::MissingLoader(asset_path.path.clone())),
+                &asset_path,
+                Task::default(),
+            );
+            return;
+        };
+
+        let settings = self
+            .loader_settings
+            .read()
+            .unwrap()
+            .get(&handle_id)
+            .cloned();
+        let handle_ref_sender = self.asset_handle_ref_channel.sender.clone();
+        let task = self
+            .task_pool
+            .spawn_cancelable(priority, move |task| async move {
+                let result = match asset_io.load(&asset_path.path).await {
+                    Ok(data) => match load_meta(asset_io.as_ref(), &asset_path, &settings).await {
+                        Ok(meta) => load_asset(
+                            loader.as_ref(),
+                            data,
+                            &asset_path,
+                            &handle_ref_sender,
+                            settings.as_deref(),
+                            meta.as_deref(),
+                        ),
+                        Err(error) => Err(error),
+                    },
+                    Err(error) => Err(error),
+                };
+
+                // nobody is waiting for a canceled load, the asset would never be unloaded
+                if !task.is_canceled() {
+                    send_result(result, &asset_path, task);
+                }
+            });
+
+        self.loading_tasks.write().unwrap().insert(handle_id, task);
+    }
+
+    /// Returns the number of strong handles to the asset
+    ///
+    /// The count is updated at the end of each frame
+    pub fn get_handle_ref_count<H: Into<HandleId>>(&self, handle: H) -> usize {
+        self.asset_handle_ref_count
+            .get(&handle.into())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns the [LoadState] of the asset pointed by the given handle
+    pub fn get_load_state<H: Into<HandleId>>(&self, handle: H) -> LoadState {
+        self.load_states
+            .read()
+            .unwrap()
+            .get(&handle.into())
+            .copied()
+            .unwrap_or(LoadState::NotLoaded)
+    }
+
+    /// Returns the [LoadState] of the asset pointed by the given handle
+    /// taking in consideration all the assets it depends on
+    ///
+    /// The asset is [LoadState::Loaded] only when it and all its dependencies are loaded,
+    /// it's [LoadState::Failed] if it or any of its dependencies failed to load.
+    /// See [LoaderContext::load]
+    pub fn get_recursive_load_state<H: Into<HandleId>>(&self, handle: H) -> LoadState {
+        let mut visited = FxHashSet::default();
+        self.recursive_load_state(handle.into(), &mut visited)
+    }
+
+    fn recursive_load_state(&self, id: HandleId, visited: &mut FxHashSet<HandleId>) -> LoadState {
+        let state = self.get_load_state(id);
+        // a dependency cycle doesn't change the state
+        if state != LoadState::Loaded || !visited.insert(id) {
+            return state;
+        }
+
+        let dependencies = self.dependencies.read().unwrap();
+        let mut state = LoadState::Loaded;
+        for dependency in dependencies.get(&id).into_iter().flatten() {
+            match self.recursive_load_state(dependency.get_id(), visited) {
+                LoadState::Failed => return LoadState::Failed,
+                LoadState::Loaded => {}
+                LoadState::NotLoaded | LoadState::Loading => state = LoadState::Loading,
+            }
+        }
+
+        state
+    }
+
+    fn set_load_state(&self, id: HandleId, state: LoadState) {
+        let mut load_states = self.load_states.write().unwrap();
+        if state == LoadState::NotLoaded {
+            load_states.remove(&id);
+        } else {
+            load_states.insert(id, state);
+        }
     }
 
     pub(crate) fn register_asset_type<T: Asset>(&self) -> Assets<T> {
@@ -291,17 +803,85 @@ impl AssetManager {
             .downcast_ref::<AssetCommandChannel<T>>()
             .unwrap();
 
+        let mut dependencies_to_load = Vec::default();
         loop {
             match asset_channel.receiver.try_recv() {
-                Ok(AssetCommand::Create(AssetCreateCommand { id, asset })) => {
+                Ok(AssetCommand::Create(AssetCreateCommand {
+                    id,
+                    asset,
+                    dependencies,
+                    file_assets,
+                    reloaded,
+                    task,
+                })) => {
+                    // the load was canceled after its result had been sent,
+                    // the asset has already been destroyed and must not be stored again
+                    if task.is_canceled() {
+                        debug!("Discard canceled asset creation. Asset id: {:?}", id);
+                        continue;
+                    }
+
                     debug!("Create asset for storage. Asset id: {:?}", id);
+                    self.loading_tasks.write().unwrap().remove(&id);
                     assets.set_untracked(id, asset);
+                    self.set_load_state(id, LoadState::Loaded);
 
-                    assets_event.publish(AssetEvent::Loaded(Handle::weak(id)))
+                    // the dependencies are kept alive by the handles stored for the parent asset
+                    let mut handles = Vec::with_capacity(dependencies.len());
+                    for (path, handle) in dependencies {
+                        dependencies_to_load.push((handle.get_id(), path));
+                        handles.push(handle);
+                    }
+                    self.dependencies.write().unwrap().insert(id, handles);
+
+                    // the assets of the same file are unloaded together when none of them is used,
+                    // holding strong handles to each other would never unload them
+                    if !file_assets.is_empty() {
+                        let group: Vec<HandleId> = file_assets
+                            .iter()
+                            .map(HandleUntyped::get_id)
+                            .chain(std::iter::once(id))
+                            .collect();
+                        let mut groups = self.file_assets.write().unwrap();
+                        for member in &group {
+                            groups.insert(*member, group.clone());
+                        }
+                    }
+
+                    if reloaded {
+                        // the asset could have been unloaded after its first load,
+                        // a temporary handle destroys it again if nobody is using it
+                        drop(Handle::<T>::strong(
+                            id,
+                            self.asset_handle_ref_channel.sender.clone(),
+                        ));
+
+                        assets_event.publish(AssetEvent::Modified(Handle::weak(id)))
+                    } else {
+                        assets_event.publish(AssetEvent::Loaded(Handle::weak(id)))
+                    }
+                }
+                Ok(AssetCommand::Fail(id, asset_error, task)) => {
+                    if task.is_canceled() {
+                        debug!("Discard canceled asset failure. Asset id: {:?}", id);
+                        continue;
+                    }
+
+                    error!("{}", asset_error);
+                    self.loading_tasks.write().unwrap().remove(&id);
+                    // a failed reload keeps the previous version of the asset
+                    if !assets.contains(&Handle::weak(id)) {
+                        self.set_load_state(id, LoadState::Failed);
+                    }
+
+                    assets_event.publish(AssetEvent::Failed(Handle::weak(id), asset_error))
                 }
                 Ok(AssetCommand::Destroy(id)) => {
                     debug!("Destroy asset for storage. Asset id: {:?}", id);
                     assets.remove(id);
+                    self.set_load_state(id, LoadState::NotLoaded);
+                    self.dependencies.write().unwrap().remove(&id);
+                    self.loader_settings.write().unwrap().remove(&id);
 
                     assets_event.publish(AssetEvent::Unloaded(Handle::weak(id)))
                 }
@@ -309,6 +889,17 @@ impl AssetManager {
                 Err(TryRecvError::Disconnected) => panic!("Asset command channel disconnected"),
             }
         }
+        drop(asset_channels);
+
+        for (id, path) in dependencies_to_load {
+            if !matches!(
+                self.get_load_state(id),
+                LoadState::Loading | LoadState::Loaded
+            ) {
+                debug!("Load asset dependency {:?}. Asset id: {:?}", path.path, id);
+                self.start_load(id, path, TaskPriority::Normal);
+            }
+        }
     }
 
     fn update_ref_count(&mut self) {
@@ -338,16 +929,37 @@ impl AssetManager {
         }
     }
 
+    fn is_unused(&self, id: &HandleId) -> bool {
+        self.asset_handle_ref_count
+            .get(id)
+            .is_none_or(|count| *count == 0)
+    }
+
     fn destroy_unused_assets(&mut self) {
-        for k in self
+        let file_assets = self.file_assets.read().unwrap();
+        let unused_assets = self
             .asset_handle_ref_count
             .iter()
-            .filter_map(|(k, v)| if *v == 0 { Some(*k) } else { None })
-            .collect::<Vec<HandleId>>()
-        {
+            .filter(|(k, v)| {
+                **v == 0
+                    && file_assets
+                        .get(k)
+                        .is_none_or(|group| group.iter().all(|id| self.is_unused(id)))
+            })
+            .map(|(k, _)| *k)
+            .collect::<Vec<HandleId>>();
+        drop(file_assets);
+
+        for k in unused_assets {
             debug!("Destroy unused asset id: {:?}", k);
 
+            if let Some(task) = self.loading_tasks.write().unwrap().remove(&k) {
+                debug!("Cancel the load of unused asset id: {:?}", k);
+                task.cancel();
+            }
+
             self.asset_handle_ref_count.remove(&k);
+            self.file_assets.write().unwrap().remove(&k);
             let asset_channels = self.asset_channels.read().unwrap();
             let asset_channel = asset_channels.get(&k.get_type()).unwrap();
             asset_channel.destroy(k);
@@ -355,6 +967,129 @@ impl AssetManager {
     }
 }
 
+/// Reads the `.meta` file of the asset, it's not needed when the settings are provided
+async fn load_meta(
+    asset_io: &dyn AssetIo,
+    asset_path: &AssetPath,
+    settings: &Option<LoaderSettings>,
+) -> Result<Option<Vec<u8>>, AssetError> {
+    if settings.is_some() {
+        return Ok(None);
+    }
+
+    let mut meta_path = asset_path.path.clone().into_os_string();
+    meta_path.push(".");
+    meta_path.push(META_EXTENSION);
+    match asset_io.load(Path::new(&meta_path)).await {
+        Ok(meta) => Ok(Some(meta)),
+        Err(AssetError::NotFound(_)) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+fn load_asset(
+    loader: &dyn AssetLoader,
+    data: Vec<u8>,
+    asset_path: &AssetPath,
+    handle_ref_sender: &Sender<HandleRef>,
+    settings: Option<&(dyn Any + Send + Sync)>,
+    meta: Option<&[u8]>,
+) -> Result<LoadedAsset, AssetError> {
+    let mut context = LoaderContext {
+        asset: None,
+        asset_path,
+        handle_ref_sender,
+        labeled_assets: Vec::default(),
+        dependencies: Vec::default(),
+        settings,
+        meta,
+    };
+
+    loader
+        .load(data, &mut context)
+        .map_err(|e| AssetError::Loader {
+            path: asset_path.full_path(),
+            message: e.to_string(),
+        })?;
+
+    Ok(LoadedAsset {
+        asset: context.asset,
+        labeled_assets: context.labeled_assets,
+        dependencies: context.dependencies,
+    })
+}
+
+/// Sends the requested asset to its storage together with all the other assets
+/// produced by the loader from the same file
+fn send_loaded_asset(
+    asset_channels: &FxHashMap<TypeId, Box<dyn AnyAssetCommandChannel>>,
+    id: HandleId,
+    asset_path: &AssetPath,
+    loaded_asset: LoadedAsset,
+    handle_ref_sender: &Sender<HandleRef>,
+    reloaded: bool,
+    task: Task,
+) {
+    let mut assets: Vec<(Option<String>, Box<dyn Asset>)> = loaded_asset
+        .labeled_assets
+        .into_iter()
+        .map(|(label, asset)| (Some(label), asset))
+        .collect();
+    if let Some(asset) = loaded_asset.asset {
+        assets.push((None, asset));
+    }
+
+    let asset_channel = &asset_channels[&id.get_type()];
+    let Some(index) = assets
+        .iter()
+        .position(|(label, _)| *label == asset_path.label)
+    else {
+        asset_channel.fail(id, AssetError::MissingAsset(asset_path.full_path()), task);
+        return;
+    };
+    let (_, asset) = assets.swap_remove(index);
+
+    // the other assets of the file are kept alive until they are grouped with the requested one
+    let mut file_assets = Vec::with_capacity(assets.len());
+    for (label, labeled_asset) in assets {
+        let type_id = labeled_asset.as_ref().as_any().type_id();
+        let labeled_path = asset_path.clone().with_label(label);
+        let Some(labeled_channel) = asset_channels.get(&type_id) else {
+            error!(
+                "Asset type of {:?} has not been registered",
+                labeled_path.full_path()
+            );
+            continue;
+        };
+
+        let labeled_id = HandleId::new_from_path_with_type(type_id, &labeled_path);
+        file_assets.push(HandleUntyped::strong(labeled_id, handle_ref_sender.clone()));
+        labeled_channel.create(
+            &labeled_path.full_path(),
+            AssetCreateCommand {
+                id: labeled_id,
+                asset: labeled_asset,
+                dependencies: Vec::default(),
+                file_assets: Vec::default(),
+                reloaded,
+                task: task.clone(),
+            },
+        );
+    }
+
+    asset_channel.create(
+        &asset_path.full_path(),
+        AssetCreateCommand {
+            id,
+            asset,
+            dependencies: loaded_asset.dependencies,
+            file_assets,
+            reloaded,
+            task,
+        },
+    );
+}
+
 pub(crate) fn update_asset_storage<T: Asset>(
     asset_manager: Res<AssetManager>,
     assets: Option<ResMut<Assets<T>>>,
@@ -365,6 +1100,10 @@ pub(crate) fn update_asset_storage<T: Asset>(
     }
 }
 
+pub(crate) fn reload_changed_assets(asset_manager: Res<AssetManager>) {
+    asset_manager.reload_changed_assets();
+}
+
 pub(crate) fn update_ref_count(mut asset_manager: ResMut<AssetManager>) {
     asset_manager.update_ref_count();
 }
@@ -375,11 +1114,20 @@ pub(crate) fn destroy_unused_assets(mut asset_manager: ResMut<AssetManager>) {
 
 #[cfg(test)]
 mod tests {
-    use std::{path::Path, sync::RwLock, thread, time::Duration};
+    use std::{
+        path::Path,
+        sync::{Arc, Barrier, RwLock},
+        thread,
+        time::Duration,
+    };
 
     use zengine_ecs::{event::EventHandler, system::EventPublisher};
+    use zengine_engine::{TaskPool, TaskPriority};
 
-    use crate::{Asset, AssetEvent, AssetLoader, AssetManager, Assets, Handle};
+    use crate::{
+        Asset, AssetError, AssetEvent, AssetLoader, AssetManager, Assets, Handle, HandleId,
+        LoadState, LoaderError,
+    };
 
     #[derive(Debug)]
     pub struct TestAsset {
@@ -401,8 +1149,86 @@ mod tests {
             &["test"]
         }
 
-        fn load(&self, data: Vec<u8>, context: &mut crate::LoaderContext) {
+        fn load(
+            &self,
+            data: Vec<u8>,
+            context: &mut crate::LoaderContext,
+        ) -> Result<(), LoaderError> {
             context.set_asset(TestAsset { _data: data });
+
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct FailingLoader {}
+    impl AssetLoader for FailingLoader {
+        fn extension(&self) -> &[&str] {
+            &["fail"]
+        }
+
+        fn load(
+            &self,
+            _data: Vec<u8>,
+            _context: &mut crate::LoaderContext,
+        ) -> Result<(), LoaderError> {
+            Err("invalid data".into())
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct TestParent {
+        children: Vec<Handle<TestAsset>>,
+    }
+    impl Asset for TestParent {
+        fn next_counter() -> u64
+        where
+            Self: Sized,
+        {
+            0
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct ParentLoader {}
+    impl AssetLoader for ParentLoader {
+        fn extension(&self) -> &[&str] {
+            &["parent"]
+        }
+
+        fn load(
+            &self,
+            data: Vec<u8>,
+            context: &mut crate::LoaderContext,
+        ) -> Result<(), LoaderError> {
+            let children = String::from_utf8(data)?
+                .lines()
+                .map(|path| context.load(path))
+                .collect();
+            context.set_asset(TestParent { children });
+
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct SheetLoader {}
+    impl AssetLoader for SheetLoader {
+        fn extension(&self) -> &[&str] {
+            &["sheet"]
+        }
+
+        fn load(
+            &self,
+            data: Vec<u8>,
+            context: &mut crate::LoaderContext,
+        ) -> Result<(), LoaderError> {
+            let image = context.add_labeled_asset("image", TestAsset { _data: data });
+            context.set_asset(TestParent {
+                children: vec![image],
+            });
+
+            Ok(())
         }
     }
 
@@ -430,6 +1256,564 @@ mod tests {
         asset_manager.destroy_unused_assets();
     }
 
+    /// Storages and event streams of the tests with parent assets
+    struct ParentStorages {
+        assets: Assets<TestAsset>,
+        parents: Assets<TestParent>,
+        stream: RwLock<EventHandler<AssetEvent<TestAsset>>>,
+        parent_stream: RwLock<EventHandler<AssetEvent<TestParent>>>,
+    }
+
+    impl ParentStorages {
+        fn new(asset_manager: &mut AssetManager) -> Self {
+            Self {
+                assets: asset_manager.register_asset_type::<TestAsset>(),
+                parents: asset_manager.register_asset_type::<TestParent>(),
+                stream: RwLock::default(),
+                parent_stream: RwLock::default(),
+            }
+        }
+
+        fn run(&mut self, asset_manager: &mut AssetManager, frames: usize) {
+            for _ in 0..frames {
+                let mut publisher = EventPublisher::new(self.stream.write().unwrap());
+                let mut parent_publisher = EventPublisher::new(self.parent_stream.write().unwrap());
+                asset_manager.update_asset_storage(&mut self.parents, &mut parent_publisher);
+                asset_manager.update_asset_storage(&mut self.assets, &mut publisher);
+                asset_manager.update_ref_count();
+                asset_manager.destroy_unused_assets();
+
+                thread::sleep(Duration::from_millis(50));
+            }
+        }
+    }
+
+    fn wait_for_state(
+        asset_manager: &mut AssetManager,
+        assets: &mut Assets<TestAsset>,
+        assets_event: &mut EventPublisher<AssetEvent<TestAsset>>,
+        handle: &Handle<TestAsset>,
+    ) -> LoadState {
+        for _ in 0..20 {
+            run_systems(asset_manager, assets, assets_event);
+            let state = asset_manager.get_load_state(handle);
+            if state != LoadState::Loading {
+                return state;
+            }
+
+            thread::sleep(Duration::from_millis(100));
+        }
+
+        asset_manager.get_load_state(handle)
+    }
+
+    #[test]
+    fn failed_loads() {
+        let dir = create_dir_and_file("file.fail");
+        let mut asset_manager = setup(dir.path());
+
+        let mut assets = asset_manager.register_asset_type::<TestAsset>();
+        asset_manager.register_loader(TestLoader {});
+        asset_manager.register_loader(FailingLoader {});
+
+        let stream = RwLock::new(EventHandler::<AssetEvent<TestAsset>>::default());
+
+        let cases = [
+            ("missing.test", AssetError::NotFound("missing.test".into())),
+            (
+                "file.unknown",
+                AssetError::MissingLoader("file.unknown".into()),
+            ),
+            (
+                "file.fail",
+                AssetError::Loader {
+                    path: "file.fail".into(),
+                    message: "invalid data".to_owned(),
+                },
+            ),
+        ];
+
+        for (path, expected) in cases {
+            let handle: Handle<TestAsset> = asset_manager.load(path);
+            assert_eq!(asset_manager.get_load_state(&handle), LoadState::Loading);
+
+            let mut publisher = EventPublisher::new(stream.write().unwrap());
+            let state = wait_for_state(&mut asset_manager, &mut assets, &mut publisher, &handle);
+            drop(publisher);
+
+            assert_eq!(state, LoadState::Failed);
+            match stream.read().unwrap().read_last() {
+                Some(AssetEvent::Failed(failed, error)) => {
+                    assert_eq!(failed.id, handle.id);
+                    assert_eq!(error, &expected);
+                }
+                event => panic!("unexpected event {:?}", event),
+            }
+        }
+    }
+
+    #[test]
+    fn dependencies() {
+        let dir = create_dir_and_file("child.test");
+        std::fs::write(dir.path().join("ok.parent"), "child.test").unwrap();
+        std::fs::write(dir.path().join("broken.parent"), "child.test\nmissing.test").unwrap();
+        let mut asset_manager = setup(dir.path());
+
+        let mut storages = ParentStorages::new(&mut asset_manager);
+        asset_manager.register_loader(TestLoader {});
+        asset_manager.register_loader(ParentLoader {});
+
+        let ok: Handle<TestParent> = asset_manager.load("ok.parent");
+        let broken: Handle<TestParent> = asset_manager.load("broken.parent");
+        storages.run(&mut asset_manager, 20);
+
+        assert_eq!(asset_manager.get_load_state(&broken), LoadState::Loaded);
+        assert_eq!(
+            asset_manager.get_recursive_load_state(&broken),
+            LoadState::Failed
+        );
+        assert_eq!(
+            asset_manager.get_recursive_load_state(&ok),
+            LoadState::Loaded
+        );
+
+        let child = storages.parents.get(&ok).unwrap().children[0].clone_as_weak();
+        assert_eq!(asset_manager.get_load_state(&child), LoadState::Loaded);
+
+        drop(ok);
+        storages.run(&mut asset_manager, 2);
+        assert_eq!(asset_manager.get_load_state(&child), LoadState::Loaded);
+
+        drop(broken);
+        storages.run(&mut asset_manager, 3);
+        assert_eq!(asset_manager.get_load_state(&child), LoadState::NotLoaded);
+    }
+
+    #[test]
+    fn labeled_assets() {
+        let dir = create_dir_and_file("file.sheet");
+        std::fs::write(dir.path().join("file.sheet"), [1, 2]).unwrap();
+        let mut asset_manager = setup(dir.path());
+
+        let mut storages = ParentStorages::new(&mut asset_manager);
+        asset_manager.register_loader(SheetLoader {});
+
+        let image: Handle<TestAsset> = asset_manager.load("file.sheet#image");
+        let missing: Handle<TestAsset> = asset_manager.load("file.sheet#missing");
+        storages.run(&mut asset_manager, 20);
+
+        assert_eq!(asset_manager.get_load_state(&missing), LoadState::Failed);
+        assert_eq!(
+            asset_manager.get_recursive_load_state(&image),
+            LoadState::Loaded
+        );
+
+        let sheet =
+            Handle::<TestParent>::weak(HandleId::new_from_path::<TestParent>(&"file.sheet".into()));
+        assert_eq!(storages.parents.get(&sheet).unwrap().children[0], image);
+
+        drop(image);
+        drop(missing);
+        storages.run(&mut asset_manager, 3);
+        assert_eq!(asset_manager.get_load_state(&sheet), LoadState::NotLoaded);
+        assert!(storages.parents.is_empty());
+        assert!(storages.assets.is_empty());
+    }
+
+    #[test]
+    fn labeled_and_root_assets() {
+        let dir = create_dir_and_file("file.sheet");
+        std::fs::write(dir.path().join("file.sheet"), [1, 2]).unwrap();
+        let mut asset_manager = setup(dir.path());
+
+        let mut storages = ParentStorages::new(&mut asset_manager);
+        asset_manager.register_loader(SheetLoader {});
+
+        let sheet: Handle<TestParent> = asset_manager.load("file.sheet");
+        let image: Handle<TestAsset> = asset_manager.load("file.sheet#image");
+        storages.run(&mut asset_manager, 20);
+
+        assert_eq!(asset_manager.get_load_state(&sheet), LoadState::Loaded);
+        assert_eq!(asset_manager.get_load_state(&image), LoadState::Loaded);
+
+        let weak_sheet = sheet.clone_as_weak();
+        drop(sheet);
+        storages.run(&mut asset_manager, 3);
+        assert_eq!(asset_manager.get_load_state(&weak_sheet), LoadState::Loaded);
+
+        drop(image);
+        storages.run(&mut asset_manager, 3);
+        assert_eq!(
+            asset_manager.get_load_state(&weak_sheet),
+            LoadState::NotLoaded
+        );
+        assert!(storages.parents.is_empty());
+        assert!(storages.assets.is_empty());
+    }
+
+    #[test]
+    fn cancel_unused_loads() {
+        let dir = create_dir_and_file("file.test");
+        let mut asset_manager = setup(dir.path());
+        let task_pool = TaskPool::new(1);
+        asset_manager.set_task_pool(task_pool.clone());
+
+        let mut assets = asset_manager.register_asset_type::<TestAsset>();
+        asset_manager.register_loader(TestLoader {});
+
+        let stream = RwLock::new(EventHandler::<AssetEvent<TestAsset>>::default());
+        let mut publisher = EventPublisher::new(stream.write().unwrap());
+
+        // keeps the only thread busy so the load stays queued
+        let barrier = Arc::new(Barrier::new(2));
+        let blocking_barrier = barrier.clone();
+        task_pool.spawn(TaskPriority::High, async move {
+            blocking_barrier.wait();
+        });
+
+        let handle: Handle<TestAsset> = asset_manager.load("file.test");
+        let id = handle.id;
+        let task = asset_manager.loading_tasks.read().unwrap()[&id].clone();
+        drop(handle);
+        run_systems(&mut asset_manager, &mut assets, &mut publisher);
+        assert!(task.is_canceled());
+
+        barrier.wait();
+        for _ in 0..20 {
+            run_systems(&mut asset_manager, &mut assets, &mut publisher);
+            if task.is_finished() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        run_systems(&mut asset_manager, &mut assets, &mut publisher);
+
+        assert!(task.is_finished());
+        assert!(assets.is_empty());
+        assert_eq!(asset_manager.get_load_state(id), LoadState::NotLoaded);
+    }
+
+    #[test]
+    fn discard_create_after_destroy() {
+        let dir = create_dir_and_file("file.test");
+        let mut asset_manager = setup(dir.path());
+
+        let mut assets = asset_manager.register_asset_type::<TestAsset>();
+        asset_manager.register_loader(TestLoader {});
+
+        let stream = RwLock::new(EventHandler::<AssetEvent<TestAsset>>::default());
+        let mut publisher = EventPublisher::new(stream.write().unwrap());
+
+        let handle: Handle<TestAsset> = asset_manager.load("file.test");
+        let id = handle.id;
+        let task = asset_manager.loading_tasks.read().unwrap()[&id].clone();
+        for _ in 0..20 {
+            if task.is_finished() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        assert!(task.is_finished());
+
+        // takes the result out of the channel to send it after the destroy command
+        let asset_channels = asset_manager.asset_channels.read().unwrap();
+        let asset_channel = asset_channels[&id.get_type()]
+            .downcast_ref::<super::AssetCommandChannel<TestAsset>>()
+            .unwrap();
+        let create = asset_channel.receiver.try_recv().unwrap();
+        let sender = asset_channel.sender.clone();
+        drop(asset_channels);
+
+        drop(handle);
+        run_systems(&mut asset_manager, &mut assets, &mut publisher);
+        assert!(task.is_canceled());
+
+        sender.send(create).unwrap();
+        run_systems(&mut asset_manager, &mut assets, &mut publisher);
+
+        assert!(assets.is_empty());
+        assert_eq!(asset_manager.get_load_state(id), LoadState::NotLoaded);
+    }
+
+    #[test]
+    fn reload_changed_assets() {
+        let dir = create_dir_and_file("file.test");
+        let mut asset_manager = setup(dir.path());
+
+        let mut assets = asset_manager.register_asset_type::<TestAsset>();
+        asset_manager.register_loader(TestLoader {});
+
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        asset_manager.changed_assets = Some(receiver);
+
+        let stream = RwLock::new(EventHandler::<AssetEvent<TestAsset>>::default());
+
+        let handle: Handle<TestAsset> = asset_manager.load("file.test");
+        let mut publisher = EventPublisher::new(stream.write().unwrap());
+        let state = wait_for_state(&mut asset_manager, &mut assets, &mut publisher, &handle);
+        drop(publisher);
+        assert_eq!(state, LoadState::Loaded);
+
+        std::fs::write(dir.path().join("file.test"), [1, 2, 3]).unwrap();
+        sender.send("file.test".into()).unwrap();
+        sender.send("file.test".into()).unwrap();
+        asset_manager.reload_changed_assets();
+
+        for _ in 0..20 {
+            let mut publisher = EventPublisher::new(stream.write().unwrap());
+            run_systems(&mut asset_manager, &mut assets, &mut publisher);
+            drop(publisher);
+            if let Some(AssetEvent::Modified(_)) = stream.read().unwrap().read_last() {
+                break;
+            }
+
+            thread::sleep(Duration::from_millis(100));
+        }
+
+        match stream.read().unwrap().read_last() {
+            Some(AssetEvent::Modified(modified)) => assert_eq!(modified.id, handle.id),
+            event => panic!("unexpected event {:?}", event),
+        }
+        assert_eq!(assets.get(&handle).unwrap()._data, vec![1, 2, 3]);
+        assert_eq!(asset_manager.get_load_state(&handle), LoadState::Loaded);
+        assert_eq!(
+            asset_manager.asset_handle_ref_count.get(&handle.id),
+            Some(&1)
+        )
+    }
+
+    #[test]
+    fn reload_unloaded_asset() {
+        let dir = create_dir_and_file("file.test");
+        let mut asset_manager = setup(dir.path());
+
+        let mut assets = asset_manager.register_asset_type::<TestAsset>();
+        asset_manager.register_loader(TestLoader {});
+
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        asset_manager.changed_assets = Some(receiver);
+
+        let stream = RwLock::new(EventHandler::<AssetEvent<TestAsset>>::default());
+        let token = stream.write().unwrap().subscribe();
+
+        let handle: Handle<TestAsset> = asset_manager.load("file.test");
+        let mut publisher = EventPublisher::new(stream.write().unwrap());
+        let state = wait_for_state(&mut asset_manager, &mut assets, &mut publisher, &handle);
+        assert_eq!(state, LoadState::Loaded);
+
+        let id = handle.id;
+        drop(handle);
+        run_systems(&mut asset_manager, &mut assets, &mut publisher);
+        run_systems(&mut asset_manager, &mut assets, &mut publisher);
+        drop(publisher);
+        assert_eq!(asset_manager.get_load_state(id), LoadState::NotLoaded);
+        stream.read().unwrap().read(&token).count();
+
+        std::fs::write(dir.path().join("file.test"), [1, 2, 3]).unwrap();
+        sender.send("file.test".into()).unwrap();
+        asset_manager.reload_changed_assets();
+
+        let mut events = Vec::default();
+        for _ in 0..20 {
+            let mut publisher = EventPublisher::new(stream.write().unwrap());
+            run_systems(&mut asset_manager, &mut assets, &mut publisher);
+            drop(publisher);
+            events.extend(
+                stream
+                    .read()
+                    .unwrap()
+                    .read(&token)
+                    .map(|event| match event {
+                        AssetEvent::Loaded(_) => "loaded",
+                        AssetEvent::Unloaded(_) => "unloaded",
+                        AssetEvent::Modified(_) => "modified",
+                        AssetEvent::Failed(..) => "failed",
+                    }),
+            );
+            if events.contains(&"unloaded") {
+                break;
+            }
+
+            thread::sleep(Duration::from_millis(100));
+        }
+
+        // nobody is using the reloaded asset, so it's unloaded again
+        assert_eq!(events, vec!["modified", "unloaded"]);
+        assert_eq!(asset_manager.get_load_state(id), LoadState::NotLoaded);
+        assert!(assets.get(&Handle::<TestAsset>::weak(id)).is_none());
+        assert_eq!(asset_manager.asset_handle_ref_count.get(&id), None);
+    }
+
+    #[test]
+    fn load_from_pak() {
+        let mut data = Vec::default();
+        crate::write_pak(&mut data, &[("sprites/file.test", [1, 2, 3])]).unwrap();
+        let pak = crate::Pak::from_bytes(data).unwrap();
+        let mut asset_manager = AssetManager::new(crate::io::PakAssetIo::new(pak));
+
+        let mut assets = asset_manager.register_asset_type::<TestAsset>();
+        asset_manager.register_loader(TestLoader {});
+
+        let stream = RwLock::new(EventHandler::<AssetEvent<TestAsset>>::default());
+        let mut publisher = EventPublisher::new(stream.write().unwrap());
+
+        let handle: Handle<TestAsset> = asset_manager.load("sprites/file.test");
+        let missing: Handle<TestAsset> = asset_manager.load("file.test");
+        let state = wait_for_state(&mut asset_manager, &mut assets, &mut publisher, &handle);
+        assert_eq!(state, LoadState::Loaded);
+        assert_eq!(assets.get(&handle).unwrap()._data, vec![1, 2, 3]);
+
+        let state = wait_for_state(&mut asset_manager, &mut assets, &mut publisher, &missing);
+        assert_eq!(state, LoadState::Failed);
+    }
+
+    #[test]
+    fn asset_sources() {
+        let memory =
+            crate::io::MemoryAssetIo::default().with_asset("file.test", [1, 2, 3].as_slice());
+        let mut asset_manager = AssetManager::new(memory);
+        asset_manager.add_embedded_asset("other.test", &[4, 5]);
+        asset_manager.add_asset_source(
+            "generated",
+            crate::io::MemoryAssetIo::default().with_asset("level.test", [6].as_slice()),
+        );
+
+        let mut assets = asset_manager.register_asset_type::<TestAsset>();
+        asset_manager.register_loader(TestLoader {});
+
+        let stream = RwLock::new(EventHandler::<AssetEvent<TestAsset>>::default());
+        let mut publisher = EventPublisher::new(stream.write().unwrap());
+
+        let file: Handle<TestAsset> = asset_manager.load("file.test");
+        let embedded: Handle<TestAsset> = asset_manager.load("embedded://other.test");
+        let generated: Handle<TestAsset> = asset_manager.load("generated://level.test");
+        let not_embedded: Handle<TestAsset> = asset_manager.load("embedded://file.test");
+        let missing: Handle<TestAsset> = asset_manager.load("embedded://missing.test");
+        let missing_source: Handle<TestAsset> = asset_manager.load("missing://file.test");
+
+        for (handle, data) in [
+            (&file, vec![1, 2, 3]),
+            (&embedded, vec![4, 5]),
+            (&generated, vec![6]),
+            (&not_embedded, vec![1, 2, 3]),
+        ] {
+            let state = wait_for_state(&mut asset_manager, &mut assets, &mut publisher, handle);
+            assert_eq!(state, LoadState::Loaded);
+            assert_eq!(assets.get(handle).unwrap()._data, data);
+        }
+
+        for handle in [&missing, &missing_source] {
+            let state = wait_for_state(&mut asset_manager, &mut assets, &mut publisher, handle);
+            assert_eq!(state, LoadState::Failed);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "reserved")]
+    fn reserved_asset_source() {
+        let mut asset_manager = AssetManager::new(crate::io::MemoryAssetIo::default());
+        asset_manager.add_asset_source("embedded", crate::io::MemoryAssetIo::default());
+    }
+
+    #[derive(Debug, Default, Clone, serde::Deserialize)]
+    pub struct ScaleSettings {
+        scale: u8,
+    }
+
+    #[derive(Debug)]
+    pub struct ScaleLoader {}
+    impl AssetLoader for ScaleLoader {
+        fn extension(&self) -> &[&str] {
+            &["scale"]
+        }
+
+        fn load(
+            &self,
+            data: Vec<u8>,
+            context: &mut crate::LoaderContext,
+        ) -> Result<(), LoaderError> {
+            let settings: ScaleSettings = context.settings()?;
+            context.set_asset(TestAsset {
+                _data: data.iter().map(|value| value * settings.scale).collect(),
+            });
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn loader_settings() {
+        let memory = crate::io::MemoryAssetIo::default()
+            .with_asset("default.scale", [1, 2].as_slice())
+            .with_asset("meta.scale", [1, 2].as_slice())
+            .with_asset("meta.scale.meta", b"(scale: 3)".as_slice())
+            .with_asset("overridden.scale", [1, 2].as_slice())
+            .with_asset("overridden.scale.meta", b"(scale: 3)".as_slice())
+            .with_asset("wrong.scale", [1, 2].as_slice())
+            .with_asset("invalid.scale", [1, 2].as_slice())
+            .with_asset("invalid.scale.meta", b"(scale: ".as_slice());
+        let mut asset_manager = AssetManager::new(memory);
+
+        let mut assets = asset_manager.register_asset_type::<TestAsset>();
+        asset_manager.register_loader(ScaleLoader {});
+
+        let stream = RwLock::new(EventHandler::<AssetEvent<TestAsset>>::default());
+        let mut publisher = EventPublisher::new(stream.write().unwrap());
+
+        let default: Handle<TestAsset> = asset_manager.load("default.scale");
+        let meta: Handle<TestAsset> = asset_manager.load("meta.scale");
+        let overridden: Handle<TestAsset> =
+            asset_manager.load_with_settings("overridden.scale", ScaleSettings { scale: 5 });
+        let invalid: Handle<TestAsset> = asset_manager.load("invalid.scale");
+        let wrong_type: Handle<TestAsset> = asset_manager.load_with_settings("wrong.scale", 5);
+
+        for (handle, data) in [
+            (&default, vec![0, 0]),
+            (&meta, vec![3, 6]),
+            (&overridden, vec![5, 10]),
+        ] {
+            let state = wait_for_state(&mut asset_manager, &mut assets, &mut publisher, handle);
+            assert_eq!(state, LoadState::Loaded);
+            assert_eq!(assets.get(handle).unwrap()._data, data);
+        }
+
+        for handle in [&invalid, &wrong_type] {
+            let state = wait_for_state(&mut asset_manager, &mut assets, &mut publisher, handle);
+            assert_eq!(state, LoadState::Failed);
+        }
+    }
+
+    #[test]
+    fn load_folder() {
+        let memory = crate::io::MemoryAssetIo::default()
+            .with_asset("levels/b.test", [2].as_slice())
+            .with_asset("levels/a.test", [1].as_slice())
+            .with_asset("levels/a.test.meta", b"()".as_slice())
+            .with_asset("levels/extra/c.test", [3].as_slice())
+            .with_asset("levels/readme.txt", b"levels".as_slice())
+            .with_asset("other.test", [4].as_slice());
+        let mut asset_manager = AssetManager::new(memory);
+
+        let mut assets = asset_manager.register_asset_type::<TestAsset>();
+        asset_manager.register_loader(TestLoader {});
+
+        let stream = RwLock::new(EventHandler::<AssetEvent<TestAsset>>::default());
+        let mut publisher = EventPublisher::new(stream.write().unwrap());
+
+        let handles = asset_manager.load_folder::<TestAsset, _>("levels").unwrap();
+        assert_eq!(handles.len(), 3);
+        for (handle, data) in handles.iter().zip([vec![1], vec![2], vec![3]]) {
+            let state = wait_for_state(&mut asset_manager, &mut assets, &mut publisher, handle);
+            assert_eq!(state, LoadState::Loaded);
+            assert_eq!(assets.get(handle).unwrap()._data, data);
+        }
+
+        assert!(matches!(
+            asset_manager.load_folder::<TestAsset, _>("missing"),
+            Err(AssetError::NotFound(_))
+        ));
+    }
+
     #[test]
     fn test() {
         let dir = create_dir_and_file("file.test");
@@ -460,6 +1844,7 @@ mod tests {
 
         let asset = assets.get(&handle);
         assert!(asset.is_some());
+        assert_eq!(asset_manager.get_load_state(&handle), LoadState::Loaded);
         assert_eq!(
             asset_manager.asset_handle_ref_count.get(&handle.id),
             Some(&1)
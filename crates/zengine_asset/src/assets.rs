@@ -141,16 +141,67 @@ impl<T: Asset> Assets<T> {
 }
 
 /// Represents a path to an asset in the file system
-#[derive(Debug)]
+///
+/// The path can contain a label after a `#` to point to one of the assets
+/// contained in the same file, like `"sheet.json#atlas"`.
+/// See [LoaderContext::add_labeled_asset](crate::LoaderContext::add_labeled_asset)
+///
+/// The path can start with the name of the asset source followed by `://`,
+/// like `"embedded://logo.png"`. Without a source the asset is loaded from
+/// the default one, usually the assets folder. An `embedded://` path that doesn't match
+/// an embedded asset is loaded from the default source too.
+/// See [AssetManager::add_asset_source](crate::AssetManager::add_asset_source)
+#[derive(Debug, Clone)]
 pub struct AssetPath {
+    pub(crate) source: Option<String>,
     pub(crate) path: PathBuf,
     pub(crate) extension: String,
+    pub(crate) label: Option<String>,
+}
+
+impl AssetPath {
+    pub(crate) fn with_label(mut self, label: Option<String>) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// Returns the path including the source and the label, if any
+    pub fn full_path(&self) -> PathBuf {
+        let mut full_path = String::default();
+        if let Some(source) = &self.source {
+            full_path.push_str(source);
+            full_path.push_str("://");
+        }
+        full_path.push_str(&self.path.to_string_lossy());
+        if let Some(label) = &self.label {
+            full_path.push('#');
+            full_path.push_str(label);
+        }
+
+        full_path.into()
+    }
 }
 
 impl From<&str> for AssetPath {
     fn from(file_path: &str) -> Self {
-        let path = std::path::Path::new(file_path);
+        let (source, file_path) = match file_path.split_once("://") {
+            Some((source, path)) => (Some(source.to_owned()), path),
+            None => (None, file_path),
+        };
+        let (path, label) = match file_path.split_once('#') {
+            Some((path, label)) => (path, Some(label.to_owned())),
+            None => (file_path, None),
+        };
+
+        AssetPath {
+            source,
+            ..AssetPath::from(std::path::Path::new(path)).with_label(label)
+        }
+    }
+}
 
+impl From<&std::path::Path> for AssetPath {
+    fn from(path: &std::path::Path) -> Self {
         let extension = path
             .extension()
             .and_then(OsStr::to_str)
@@ -158,8 +209,10 @@ impl From<&str> for AssetPath {
             .to_owned();
 
         AssetPath {
+            source: None,
             path: path.into(),
             extension,
+            label: None,
         }
     }
 }
@@ -0,0 +1,34 @@
+//! Bundles a folder of assets in a pak archive
+//!
+//! Usage: `zengine_pak <assets folder> <output pak>`
+
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    process::ExitCode,
+};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [dir, output] = args.as_slice() else {
+        eprintln!("Usage: zengine_pak <assets folder> <output pak>");
+        return ExitCode::FAILURE;
+    };
+
+    let result = File::create(output).and_then(|file| {
+        let mut writer = BufWriter::new(file);
+        zengine_asset::write_pak_from_dir(&mut writer, dir)?;
+        writer.flush()
+    });
+
+    match result {
+        Ok(()) => {
+            println!("Assets of {} bundled in {}", dir, output);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Could not write the pak {}: {}", output, error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    path::PathBuf,
+};
+
+/// The error returned by an [AssetLoader](crate::AssetLoader) that fails to process an asset
+pub type LoaderError = Box<dyn Error + Send + Sync>;
+
+/// An error that occurred while loading an asset.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AssetError {
+    /// There is no [AssetLoader](crate::AssetLoader) registered
+    /// for the extension of the asset
+    MissingLoader(PathBuf),
+    /// There is no asset source registered with the name used in the asset path
+    MissingSource(PathBuf),
+    /// The asset file doesn't exist
+    NotFound(PathBuf),
+    /// The asset data couldn't be read
+    Io { path: PathBuf, message: String },
+    /// The [AssetLoader](crate::AssetLoader) failed to process the asset data
+    Loader { path: PathBuf, message: String },
+    /// The [AssetLoader](crate::AssetLoader) didn't produce an asset
+    MissingAsset(PathBuf),
+    /// The [AssetLoader](crate::AssetLoader) produced an asset of a different type
+    /// than the requested one
+    WrongType {
+        path: PathBuf,
+        expected: &'static str,
+    },
+}
+
+impl AssetError {
+    /// Returns the path of the asset that failed to load
+    pub fn path(&self) -> &PathBuf {
+        match self {
+            AssetError::MissingLoader(path)
+            | AssetError::MissingSource(path)
+            | AssetError::NotFound(path)
+            | AssetError::Io { path, .. }
+            | AssetError::Loader { path, .. }
+            | AssetError::MissingAsset(path)
+            | AssetError::WrongType { path, .. } => path,
+        }
+    }
+}
+
+impl Display for AssetError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            AssetError::MissingLoader(path) => {
+                write!(f, "Asset loader not found for asset {:?}", path)
+            }
+            AssetError::MissingSource(path) => {
+                write!(f, "Asset source not found for asset {:?}", path)
+            }
+            AssetError::NotFound(path) => write!(f, "Asset file {:?} not found", path),
+            AssetError::Io { path, message } => {
+                write!(f, "Could not read asset {:?}: {}", path, message)
+            }
+            AssetError::Loader { path, message } => {
+                write!(f, "Could not load asset {:?}: {}", path, message)
+            }
+            AssetError::MissingAsset(path) => {
+                write!(f, "The loader didn't produce an asset for {:?}", path)
+            }
+            AssetError::WrongType { path, expected } => {
+                write!(f, "Asset {:?} is not of type {}", path, expected)
+            }
+        }
+    }
+}
+
+impl Error for AssetError {}
@@ -25,10 +25,18 @@ pub enum HandleId {
 impl HandleId {
     /// Creates a new HandleId from a given [AssetPath]
     pub fn new_from_path<T: Asset>(asset_path: &AssetPath) -> Self {
-        let type_id = TypeId::of::<T>();
+        Self::new_from_path_with_type(TypeId::of::<T>(), asset_path)
+    }
 
+    pub(crate) fn new_from_path_with_type(type_id: TypeId, asset_path: &AssetPath) -> Self {
         let mut hasher = ahash::AHasher::default();
+        if let Some(source) = &asset_path.source {
+            source.hash(&mut hasher);
+        }
         asset_path.path.hash(&mut hasher);
+        if let Some(label) = &asset_path.label {
+            label.hash(&mut hasher);
+        }
         let id: u64 = hasher.finish();
 
         Self::FromPath(type_id, id)
@@ -66,6 +74,12 @@ impl<T: Asset> From<Handle<T>> for HandleId {
     }
 }
 
+impl<T: Asset> From<&Handle<T>> for HandleId {
+    fn from(value: &Handle<T>) -> Self {
+        value.id
+    }
+}
+
 #[derive(PartialEq, Debug)]
 pub(crate) enum HandleRef {
     Increment(HandleId),
@@ -197,6 +211,15 @@ impl<T: Asset> Handle<T> {
         matches!(self.handle_type, HandleType::Weak)
     }
 
+    /// Converts the handle into an [HandleUntyped] keeping its strong or weak state
+    pub fn into_untyped(mut self) -> HandleUntyped {
+        HandleUntyped {
+            id: self.id,
+            // the original handle becomes weak so its drop doesn't release the asset
+            handle_type: std::mem::take(&mut self.handle_type),
+        }
+    }
+
     /// Makes this handle Strong if it wasn’t already
     ///
     /// This method requires the corresponding [Assets] storage
@@ -210,6 +233,94 @@ impl<T: Asset> Handle<T> {
     }
 }
 
+/// A handle that doesn't know the type of the [Asset] it points to
+///
+/// It works like a [Handle], a strong untyped handle keeps the asset loaded.
+/// It can be converted back to a [Handle] using [HandleUntyped::typed]
+#[derive(Debug)]
+pub struct HandleUntyped {
+    pub(crate) id: HandleId,
+    handle_type: HandleType,
+}
+
+impl HandleUntyped {
+    pub(crate) fn strong(id: HandleId, handle_ref_sender: Sender<HandleRef>) -> Self {
+        handle_ref_sender.send(HandleRef::Increment(id)).unwrap();
+        debug!("Create a strong untyped handle id: {:?}", id);
+        Self {
+            id,
+            handle_type: HandleType::Strong(handle_ref_sender),
+        }
+    }
+
+    /// Get the handle id
+    pub fn get_id(&self) -> HandleId {
+        self.id
+    }
+
+    /// Checks if the handle is a strong one
+    pub fn is_strong(&self) -> bool {
+        matches!(self.handle_type, HandleType::Strong(_))
+    }
+
+    /// Checks if the handle is a weak one
+    pub fn is_weak(&self) -> bool {
+        matches!(self.handle_type, HandleType::Weak)
+    }
+
+    /// Converts the handle into a typed [Handle] keeping its strong or weak state
+    ///
+    /// # Panics
+    /// Panics if the handle doesn't point to an asset of type `T`
+    pub fn typed<T: Asset>(mut self) -> Handle<T> {
+        assert_eq!(
+            self.id.get_type(),
+            TypeId::of::<T>(),
+            "Handle {:?} doesn't point to an asset of type {}",
+            self.id,
+            std::any::type_name::<T>()
+        );
+
+        Handle {
+            id: self.id,
+            handle_type: std::mem::take(&mut self.handle_type),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl Clone for HandleUntyped {
+    fn clone(&self) -> Self {
+        let handle_type = match &self.handle_type {
+            HandleType::Strong(sender) => {
+                sender.send(HandleRef::Increment(self.id)).unwrap();
+                HandleType::Strong(sender.clone())
+            }
+            HandleType::Weak => HandleType::Weak,
+        };
+
+        Self {
+            id: self.id,
+            handle_type,
+        }
+    }
+}
+
+impl Drop for HandleUntyped {
+    fn drop(&mut self) {
+        if let HandleType::Strong(sender) = &self.handle_type {
+            let _res = sender.send(HandleRef::Decrement(self.id));
+            debug!("Drop a strong untyped handle id: {:?}", self.id);
+        }
+    }
+}
+
+impl From<&HandleUntyped> for HandleId {
+    fn from(value: &HandleUntyped) -> Self {
+        value.id
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct HandleRefChannel {
     pub sender: Sender<HandleRef>,
@@ -262,6 +373,12 @@ mod tests {
 
         let different_id = HandleId::new_from_path::<TestAsset2>(&"path1.txt".into());
         assert_ne!(id, different_id);
+
+        let labeled_id = HandleId::new_from_path::<TestAsset1>(&"path1.txt#label".into());
+        assert_ne!(id, labeled_id);
+
+        let embedded_id = HandleId::new_from_path::<TestAsset1>(&"embedded://path1.txt".into());
+        assert_ne!(id, embedded_id);
     }
 
     #[test]
@@ -381,6 +498,31 @@ mod tests {
         assert_eq!(handle_ref, Err(TryRecvError::Empty));
     }
 
+    #[test]
+    fn untyped_handle_keeps_the_ref_count() {
+        let (sender, receiver) = crossbeam_channel::unbounded::<HandleRef>();
+
+        let id = HandleId::new_from_path::<TestAsset1>(&"path1.txt".into());
+        let untyped = Handle::<TestAsset1>::strong(id, sender).into_untyped();
+        assert!(untyped.is_strong());
+        assert_eq!(receiver.try_recv(), Ok(HandleRef::Increment(id)));
+        assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+
+        let handle: Handle<TestAsset1> = untyped.typed();
+        assert!(handle.is_strong());
+        assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+
+        drop(handle);
+        assert_eq!(receiver.try_recv(), Ok(HandleRef::Decrement(id)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn untyped_handle_of_a_different_type() {
+        let id = HandleId::new_from_path::<TestAsset1>(&"path1.txt".into());
+        let _handle: Handle<TestAsset2> = Handle::<TestAsset1>::weak(id).into_untyped().typed();
+    }
+
     #[test]
     fn making_a_weak_handle_a_strong_one_increment_ref_counter() {
         let (sender, receiver) = crossbeam_channel::unbounded::<HandleRef>();
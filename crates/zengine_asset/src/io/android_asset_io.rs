@@ -2,22 +2,30 @@ use std::{ffi::CString, path::Path};
 
 use async_trait::async_trait;
 
+use crate::AssetError;
+
 use super::AssetIo;
 
+/// An [AssetIo] that reads the assets packaged in the Android application
 #[derive(Default, Debug)]
 pub struct AndroidAssetIo;
 
 #[async_trait]
 impl AssetIo for AndroidAssetIo {
-    async fn load(&self, asset_path: &Path) -> Vec<u8> {
+    async fn load(&self, asset_path: &Path) -> Result<Vec<u8>, AssetError> {
         let full_path = asset_path;
 
         let asset_manager = ndk_glue::native_activity().asset_manager();
-        let mut opened_asset = asset_manager
-            .open(&CString::new(full_path.to_str().unwrap()).unwrap())
-            .unwrap();
+        let mut opened_asset = full_path
+            .to_str()
+            .and_then(|path| CString::new(path).ok())
+            .and_then(|path| asset_manager.open(&path))
+            .ok_or_else(|| AssetError::NotFound(asset_path.to_path_buf()))?;
 
-        let bytes = opened_asset.get_buffer().unwrap();
-        bytes.to_vec()
+        let bytes = opened_asset.get_buffer().map_err(|e| AssetError::Io {
+            path: asset_path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        Ok(bytes.to_vec())
     }
 }
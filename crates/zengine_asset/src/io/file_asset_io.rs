@@ -4,26 +4,36 @@ use std::{
 };
 
 use async_trait::async_trait;
+use crossbeam_channel::Sender;
+
+use crate::AssetError;
 
 use super::AssetIo;
 
+/// An [AssetIo] that reads the assets from a folder of the filesystem
 #[derive(Debug)]
 pub struct FileAssetIo {
     base_path: PathBuf,
+    #[cfg(feature = "hot_reload")]
+    watcher: std::sync::Mutex<Option<notify::RecommendedWatcher>>,
 }
 
 impl Default for FileAssetIo {
     fn default() -> Self {
-        Self {
-            base_path: Self::get_base_path(),
-        }
+        Self::from_full_path(Self::get_base_path())
     }
 }
 
 impl FileAssetIo {
     pub fn new<P: AsRef<Path>>(base_path: P) -> Self {
+        Self::from_full_path(Self::get_base_path().join(base_path.as_ref()))
+    }
+
+    fn from_full_path(base_path: PathBuf) -> Self {
         Self {
-            base_path: Self::get_base_path().join(base_path.as_ref()),
+            base_path,
+            #[cfg(feature = "hot_reload")]
+            watcher: std::sync::Mutex::default(),
         }
     }
 
@@ -42,16 +52,112 @@ impl FileAssetIo {
     }
 }
 
+fn collect_files(base_path: &Path, dir: &Path, paths: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(base_path, &path, paths)?;
+        } else if let Ok(relative_path) = path.strip_prefix(base_path) {
+            paths.push(relative_path.to_path_buf());
+        }
+    }
+
+    Ok(())
+}
+
 #[async_trait]
 impl AssetIo for FileAssetIo {
-    async fn load(&self, asset_path: &Path) -> Vec<u8> {
+    async fn load(&self, asset_path: &Path) -> Result<Vec<u8>, AssetError> {
         let full_path = self.base_path.join(asset_path);
         let full_path = full_path.as_path();
         let data = async move {
-            std::fs::read(full_path)
-                .unwrap_or_else(|e| panic!("Could not load file {:?}: {}", &full_path, e))
+            std::fs::read(full_path).map_err(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => AssetError::NotFound(asset_path.to_path_buf()),
+                _ => AssetError::Io {
+                    path: asset_path.to_path_buf(),
+                    message: e.to_string(),
+                },
+            })
         };
 
         data.await
     }
+
+    fn read_directory(&self, path: &Path) -> Result<Vec<PathBuf>, AssetError> {
+        let mut paths = Vec::default();
+        collect_files(&self.base_path, &self.base_path.join(path), &mut paths).map_err(
+            |e| match e.kind() {
+                std::io::ErrorKind::NotFound => AssetError::NotFound(path.to_path_buf()),
+                _ => AssetError::Io {
+                    path: path.to_path_buf(),
+                    message: e.to_string(),
+                },
+            },
+        )?;
+
+        Ok(paths)
+    }
+
+    #[cfg(feature = "hot_reload")]
+    fn watch_for_changes(&self, changes: Sender<PathBuf>) {
+        use notify::{EventKind, RecursiveMode, Watcher};
+
+        let base_path = self
+            .base_path
+            .canonicalize()
+            .unwrap_or_else(|_| self.base_path.clone());
+        let watched_path = base_path.clone();
+
+        let watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| match event {
+                Ok(event) if matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_)) => {
+                    for path in event.paths {
+                        if let Ok(relative_path) = path.strip_prefix(&base_path) {
+                            let _res = changes.send(relative_path.to_path_buf());
+                        }
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => log::warn!("Asset watcher error: {}", e),
+            })
+            .and_then(|mut watcher| {
+                watcher.watch(&watched_path, RecursiveMode::Recursive)?;
+                Ok(watcher)
+            });
+
+        match watcher {
+            Ok(watcher) => {
+                log::debug!("Watching for changes in {:?}", watched_path);
+                self.watcher.lock().unwrap().replace(watcher);
+            }
+            Err(e) => log::error!("Could not watch {:?} for changes: {}", watched_path, e),
+        }
+    }
+
+    #[cfg(not(feature = "hot_reload"))]
+    fn watch_for_changes(&self, _changes: Sender<PathBuf>) {
+        log::warn!("Watching for changes requires the `hot_reload` feature");
+    }
+}
+
+#[cfg(all(test, feature = "hot_reload"))]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use crate::io::{AssetIo, FileAssetIo};
+
+    #[test]
+    fn watch_for_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("sprites")).unwrap();
+        let asset_io = FileAssetIo::new(dir.path());
+
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        asset_io.watch_for_changes(sender);
+
+        std::fs::write(dir.path().join("sprites/file.test"), [1]).unwrap();
+
+        let changed = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(changed, PathBuf::from("sprites/file.test"));
+    }
 }
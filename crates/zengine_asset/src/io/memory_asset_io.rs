@@ -0,0 +1,76 @@
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use rustc_hash::FxHashMap;
+
+use crate::{pak::entry_name, AssetError};
+
+use super::{entries_in_directory, AssetIo};
+
+/// An [AssetIo] that reads the assets from memory
+///
+/// The assets are added programmatically, which is useful for tests
+/// and for assets embedded in the executable.
+/// Cloning it shares the same assets
+#[derive(Debug, Default, Clone)]
+pub struct MemoryAssetIo {
+    assets: Arc<RwLock<FxHashMap<String, Cow<'static, [u8]>>>>,
+}
+
+impl MemoryAssetIo {
+    /// Adds an asset at the given relative path, replacing the previous one
+    ///
+    /// # Panics
+    /// Panics if the path is not a relative path
+    pub fn insert<P: AsRef<Path>, D: Into<Cow<'static, [u8]>>>(&self, path: P, data: D) {
+        let name = entry_name(path.as_ref())
+            .unwrap_or_else(|| panic!("{:?} is not a valid relative asset path", path.as_ref()));
+
+        self.assets.write().unwrap().insert(name, data.into());
+    }
+
+    /// Returns the memory asset I/O with an asset added at the given relative path
+    pub fn with_asset<P: AsRef<Path>, D: Into<Cow<'static, [u8]>>>(self, path: P, data: D) -> Self {
+        self.insert(path, data);
+        self
+    }
+
+    /// Removes the asset at the given relative path,
+    /// returns `true` if the asset was present
+    pub fn remove<P: AsRef<Path>>(&self, path: P) -> bool {
+        entry_name(path.as_ref())
+            .and_then(|name| self.assets.write().unwrap().remove(&name))
+            .is_some()
+    }
+
+    /// Returns `true` if there is an asset at the given relative path
+    pub fn contains<P: AsRef<Path>>(&self, path: P) -> bool {
+        entry_name(path.as_ref())
+            .is_some_and(|name| self.assets.read().unwrap().contains_key(&name))
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl AssetIo for MemoryAssetIo {
+    async fn load(&self, asset_path: &Path) -> Result<Vec<u8>, AssetError> {
+        entry_name(asset_path)
+            .and_then(|name| {
+                self.assets
+                    .read()
+                    .unwrap()
+                    .get(&name)
+                    .map(|data| data.to_vec())
+            })
+            .ok_or_else(|| AssetError::NotFound(asset_path.to_path_buf()))
+    }
+
+    fn read_directory(&self, path: &Path) -> Result<Vec<PathBuf>, AssetError> {
+        let assets = self.assets.read().unwrap();
+        entries_in_directory(assets.keys().map(String::as_str), path)
+    }
+}
@@ -1,5 +1,8 @@
 use async_trait::async_trait;
-use std::path::Path;
+use crossbeam_channel::Sender;
+use std::path::{Path, PathBuf};
+
+use crate::AssetError;
 
 #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
 mod file_asset_io;
@@ -7,10 +10,56 @@ mod file_asset_io;
 #[cfg(target_arch = "wasm32")]
 mod wasm_asset_io;
 
+mod memory_asset_io;
+mod pak_asset_io;
+
+/// Reads the raw data of the assets from a source, like the filesystem or an archive
+///
+/// See [AssetManager::add_asset_source](crate::AssetManager::add_asset_source)
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait AssetIo: std::fmt::Debug + Send + Sync + 'static {
-    async fn load(&self, asset_path: &Path) -> Vec<u8>;
+    /// Reads the raw data of the asset at the given path
+    async fn load(&self, asset_path: &Path) -> Result<Vec<u8>, AssetError>;
+
+    /// Returns the relative paths of all the assets in the given directory
+    /// and in its subdirectories
+    fn read_directory(&self, path: &Path) -> Result<Vec<PathBuf>, AssetError> {
+        Err(AssetError::Io {
+            path: path.to_path_buf(),
+            message: format!("Reading a directory is not supported by {:?}", self),
+        })
+    }
+
+    /// Starts watching the assets for changes.
+    /// The relative paths of the changed assets are sent to `changes`
+    fn watch_for_changes(&self, _changes: Sender<PathBuf>) {
+        log::warn!("Watching for changes is not supported by {:?}", self);
+    }
+}
+
+/// Returns the entries that are inside the directory,
+/// for the sources where the entries are relative paths joined by `/`
+fn entries_in_directory<'a, I: Iterator<Item = &'a str>>(
+    entries: I,
+    path: &Path,
+) -> Result<Vec<PathBuf>, AssetError> {
+    let directory =
+        crate::pak::entry_name(path).ok_or_else(|| AssetError::NotFound(path.to_path_buf()))?;
+    if directory.is_empty() {
+        return Ok(entries.map(PathBuf::from).collect());
+    }
+
+    let prefix = format!("{}/", directory);
+    let paths: Vec<PathBuf> = entries
+        .filter(|entry| entry.starts_with(&prefix))
+        .map(PathBuf::from)
+        .collect();
+    if paths.is_empty() {
+        return Err(AssetError::NotFound(path.to_path_buf()));
+    }
+
+    Ok(paths)
 }
 
 #[cfg(target_os = "android")]
@@ -22,5 +71,8 @@ pub use file_asset_io::FileAssetIo;
 #[cfg(target_arch = "wasm32")]
 pub use wasm_asset_io::WasmAssetIo;
 
+pub use memory_asset_io::MemoryAssetIo;
+pub use pak_asset_io::PakAssetIo;
+
 #[cfg(target_os = "android")]
 pub use android_asset_io::AndroidAssetIo;
@@ -0,0 +1,34 @@
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+
+use crate::{AssetError, Pak};
+
+use super::{entries_in_directory, AssetIo};
+
+/// An [AssetIo] that reads the assets from a [Pak] archive
+#[derive(Debug)]
+pub struct PakAssetIo {
+    pak: Pak,
+}
+
+impl PakAssetIo {
+    pub fn new(pak: Pak) -> Self {
+        Self { pak }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl AssetIo for PakAssetIo {
+    async fn load(&self, asset_path: &Path) -> Result<Vec<u8>, AssetError> {
+        self.pak
+            .get(asset_path)
+            .map(<[u8]>::to_vec)
+            .ok_or_else(|| AssetError::NotFound(asset_path.to_path_buf()))
+    }
+
+    fn read_directory(&self, path: &Path) -> Result<Vec<PathBuf>, AssetError> {
+        entries_in_directory(self.pak.paths(), path)
+    }
+}
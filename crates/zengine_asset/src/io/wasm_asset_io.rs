@@ -3,8 +3,11 @@ use js_sys::Uint8Array;
 use std::path::{Path, PathBuf};
 use wasm_bindgen::JsCast;
 
+use crate::AssetError;
+
 use super::AssetIo;
 
+/// An [AssetIo] that fetches the assets from the web server
 #[derive(Default, Debug)]
 pub struct WasmAssetIo {
     base_path: PathBuf,
@@ -18,9 +21,13 @@ impl WasmAssetIo {
 
 #[async_trait(?Send)]
 impl AssetIo for WasmAssetIo {
-    async fn load(&self, asset_path: &Path) -> Vec<u8> {
+    async fn load(&self, asset_path: &Path) -> Result<Vec<u8>, AssetError> {
         let full_path = self.base_path.join(asset_path);
         let full_path = full_path.as_path();
+        let io_error = |e: wasm_bindgen::JsValue| AssetError::Io {
+            path: asset_path.to_path_buf(),
+            message: format!("{:?}", e),
+        };
 
         let window = web_sys::window().unwrap();
 
@@ -28,13 +35,18 @@ impl AssetIo for WasmAssetIo {
             window.fetch_with_str(full_path.to_str().unwrap()),
         )
         .await
-        .unwrap();
+        .map_err(io_error)?;
 
         let resp: web_sys::Response = resp_value.dyn_into().unwrap();
-        let resp_value = wasm_bindgen_futures::JsFuture::from(resp.array_buffer().unwrap())
-            .await
-            .unwrap();
+        if resp.status() == 404 {
+            return Err(AssetError::NotFound(asset_path.to_path_buf()));
+        }
+
+        let resp_value =
+            wasm_bindgen_futures::JsFuture::from(resp.array_buffer().map_err(io_error)?)
+                .await
+                .map_err(io_error)?;
 
-        Uint8Array::new(&resp_value).to_vec()
+        Ok(Uint8Array::new(&resp_value).to_vec())
     }
 }
@@ -1,17 +1,32 @@
+mod asset_collection;
 mod asset_manager;
 mod assets;
+mod error;
 mod handle;
-mod io;
-mod io_task;
+pub mod io;
+mod loading_tracker;
+mod pak;
+#[cfg(test)]
+mod test_utils;
 
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
+use io::AssetIo;
+
+pub use asset_collection::*;
 pub use asset_manager::*;
 pub use assets::*;
+pub use error::*;
 pub use handle::*;
-use zengine_engine::{Engine, Module, Stage};
+pub use loading_tracker::*;
+pub use pak::*;
+use zengine_ecs::{system::ResMut, World};
+use zengine_engine::{Engine, Module, Stage, TaskPool};
 
-/// Asset Events fired when an asset has been loaded or unloaded
+/// Asset Events fired when an asset has been loaded, unloaded or failed to load
 #[derive(Debug)]
 pub enum AssetEvent<T: Asset> {
     /// Asset Loaded from disk, contains a weak [Handle] to the asset that
@@ -19,6 +34,18 @@ pub enum AssetEvent<T: Asset> {
     Loaded(Handle<T>),
     /// Asset Unloaded from the [Assets] storage, contains a weak [Handle] to the asset.
     Unloaded(Handle<T>),
+    /// Asset reloaded after its file has changed, contains a weak [Handle] to the asset.
+    /// See [AssetManager::watch_for_changes]
+    Modified(Handle<T>),
+    /// Asset failed to load, contains a weak [Handle] to the asset and the error
+    Failed(Handle<T>, AssetError),
+}
+
+enum AssetSource {
+    Folder(PathBuf),
+    #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+    Pak(PathBuf),
+    EmbeddedPak(&'static [u8]),
 }
 
 /// Adds assets support to the engine
@@ -26,7 +53,9 @@ pub enum AssetEvent<T: Asset> {
 /// Assets are typed collections, which are added as Resources.
 #[derive(Default)]
 pub struct AssetModule {
-    asset_base_path: Option<PathBuf>,
+    source: Option<AssetSource>,
+    asset_sources: Vec<(String, Arc<dyn AssetIo>)>,
+    watch_for_changes: bool,
 }
 
 impl AssetModule {
@@ -36,30 +65,141 @@ impl AssetModule {
     /// `asset_base_path`
     pub fn new<P: AsRef<Path>>(asset_base_path: P) -> Self {
         Self {
-            asset_base_path: Some(asset_base_path.as_ref().to_path_buf()),
+            source: Some(AssetSource::Folder(asset_base_path.as_ref().to_path_buf())),
+            asset_sources: Vec::default(),
+            watch_for_changes: false,
+        }
+    }
+
+    /// Configure the [AssetModule] to load the assets from a [Pak] file
+    ///
+    /// The `pak_path` is relative to the directory of the Application
+    ///
+    /// # Panics
+    /// The module init panics if the pak can't be read
+    #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+    pub fn from_pak<P: AsRef<Path>>(pak_path: P) -> Self {
+        Self {
+            source: Some(AssetSource::Pak(pak_path.as_ref().to_path_buf())),
+            asset_sources: Vec::default(),
+            watch_for_changes: false,
+        }
+    }
+
+    /// Configure the [AssetModule] to load the assets from a [Pak]
+    /// embedded in the executable
+    ///
+    /// # Example
+    /// ```ignore
+    /// AssetModule::from_embedded_pak(include_bytes!("../assets.pak"))
+    /// ```
+    ///
+    /// # Panics
+    /// The module init panics if the pak is not valid
+    pub fn from_embedded_pak(pak: &'static [u8]) -> Self {
+        Self {
+            source: Some(AssetSource::EmbeddedPak(pak)),
+            asset_sources: Vec::default(),
+            watch_for_changes: false,
         }
     }
+
+    /// Adds a named asset source, the assets of the source are loaded
+    /// using a path like `"name://logo.png"`.
+    /// See [AssetManager::add_asset_source]
+    ///
+    /// The `embedded://` prefix is reserved to the embedded assets,
+    /// falling back to the default source when the path is not embedded.
+    /// See [embedded_asset]
+    ///
+    /// # Example
+    /// ```
+    /// use zengine_asset::{io::MemoryAssetIo, AssetModule};
+    ///
+    /// let generated = MemoryAssetIo::default().with_asset("level.txt", b"1 2 3".as_slice());
+    /// AssetModule::new("assets").with_asset_source("generated", generated);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the name is `embedded`
+    pub fn with_asset_source<T: AssetIo>(mut self, name: &str, asset_io: T) -> Self {
+        assert!(
+            name != EMBEDDED_SOURCE,
+            "The asset source name \"{}\" is reserved to the embedded assets",
+            EMBEDDED_SOURCE
+        );
+        self.asset_sources
+            .push((name.to_owned(), Arc::new(asset_io)));
+        self
+    }
+
+    /// Reloads the assets when their files change on disk.
+    /// See [AssetManager::watch_for_changes]
+    pub fn watch_for_changes(mut self) -> Self {
+        self.watch_for_changes = true;
+        self
+    }
 }
 
 impl Module for AssetModule {
     fn init(self, engine: &mut zengine_engine::Engine) {
-        if let Some(asset_base_path) = self.asset_base_path {
-            log::trace!("base asset path {:?}", asset_base_path);
+        // the assets are loaded using the task pool shared with the rest of the engine
+        let task_pool = engine
+            .world
+            .get_resource::<TaskPool>()
+            .map(|task_pool| TaskPool::clone(&task_pool));
+        let task_pool = task_pool.unwrap_or_else(|| {
+            let task_pool = TaskPool::default();
+            engine.world.create_resource(task_pool.clone());
+            task_pool
+        });
 
-            #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
-            let asset_io = crate::io::FileAssetIo::new(asset_base_path);
+        // assets and loaders could have been registered before the module init
+        let mut asset_manager = asset_manager(&mut engine.world);
+        asset_manager.set_task_pool(task_pool);
 
-            #[cfg(target_arch = "wasm32")]
-            let asset_io = crate::io::WasmAssetIo::new(asset_base_path);
+        match self.source {
+            Some(AssetSource::Folder(asset_base_path)) => {
+                log::trace!("base asset path {:?}", asset_base_path);
 
-            #[cfg(target_os = "android")]
-            let asset_io = crate::io::AndroidAssetIo::default();
+                #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+                let asset_io = crate::io::FileAssetIo::new(asset_base_path);
 
-            engine.world.create_resource(AssetManager::new(asset_io));
-        } else {
-            engine.world.create_resource(AssetManager::default());
+                #[cfg(target_arch = "wasm32")]
+                let asset_io = crate::io::WasmAssetIo::new(asset_base_path);
+
+                #[cfg(target_os = "android")]
+                let asset_io = crate::io::AndroidAssetIo::default();
+
+                asset_manager.set_asset_io(asset_io);
+            }
+            #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+            Some(AssetSource::Pak(pak_path)) => {
+                let pak_path = crate::io::FileAssetIo::get_base_path().join(pak_path);
+                log::trace!("asset pak {:?}", pak_path);
+
+                let pak = Pak::open(&pak_path).unwrap_or_else(|e| {
+                    panic!("Could not read the asset pak {:?}: {}", pak_path, e)
+                });
+                asset_manager.set_asset_io(crate::io::PakAssetIo::new(pak));
+            }
+            Some(AssetSource::EmbeddedPak(pak)) => {
+                let pak = Pak::from_bytes(pak)
+                    .unwrap_or_else(|e| panic!("Could not read the embedded asset pak: {}", e));
+                asset_manager.set_asset_io(crate::io::PakAssetIo::new(pak));
+            }
+            None => {}
+        }
+        for (name, asset_io) in self.asset_sources {
+            asset_manager.add_asset_source_arc(&name, asset_io);
+        }
+        if self.watch_for_changes {
+            asset_manager.watch_for_changes();
         }
+        drop(asset_manager);
 
+        engine.add_system_into_stage(reload_changed_assets, Stage::PreUpdate);
+        engine.add_system_into_stage(update_loading_tracker, Stage::PreUpdate);
         engine.add_system_into_stage(update_ref_count, Stage::PostUpdate);
         engine.add_system_into_stage(destroy_unused_assets, Stage::PostUpdate);
     }
@@ -79,6 +219,50 @@ pub trait AssetExtension {
     ///
     /// This method register an asset loader to the [AssetManager]
     fn add_asset_loader<T: AssetLoader>(&mut self, loader: T) -> &mut Self;
+
+    /// Add an asset embedded in the executable, see [embedded_asset]
+    fn add_embedded_asset<P: AsRef<Path>>(&mut self, path: P, data: &'static [u8]) -> &mut Self;
+
+    /// Add an [AssetCollection] to the engine
+    ///
+    /// The collection is loaded and added as a resource during the [Startup Stage](Stage::Startup),
+    /// after the [AssetModule] has been initialized, so it's not available to the other startup systems.
+    /// Then an [AssetCollectionEvent] is published when all its assets are ready
+    fn add_asset_collection<C: AssetCollection>(&mut self) -> &mut Self;
+}
+
+/// Embeds an asset file in the executable using `include_bytes!`
+///
+/// The asset can then be loaded with the `embedded://` prefix.
+/// The file path is relative to the current source file, like in `include_bytes!`,
+/// and it's used also as asset path unless a different one is provided.
+///
+/// # Example
+/// ```ignore
+/// // loaded with "embedded://logo.png"
+/// embedded_asset!(engine, "logo.png");
+/// // loaded with "embedded://branding/logo.png"
+/// embedded_asset!(engine, "branding/logo.png", "../assets/branding/logo.png");
+/// ```
+#[macro_export]
+macro_rules! embedded_asset {
+    ($engine:expr, $path:literal) => {
+        $crate::embedded_asset!($engine, $path, $path)
+    };
+    ($engine:expr, $asset_path:literal, $file_path:literal) => {{
+        use $crate::AssetExtension as _;
+        $engine.add_embedded_asset($asset_path, include_bytes!($file_path))
+    }};
+}
+
+/// Returns the [AssetManager] creating a default one if the [AssetModule]
+/// has not been initialized yet
+fn asset_manager(world: &mut World) -> ResMut<'_, AssetManager> {
+    if world.get_resource::<AssetManager>().is_none() {
+        world.create_resource(AssetManager::default());
+    }
+
+    world.get_mut_resource::<AssetManager>().unwrap()
 }
 
 impl AssetExtension for Engine {
@@ -87,7 +271,7 @@ impl AssetExtension for Engine {
             self
         } else {
             let assets = {
-                let asset_manager = self.world.get_resource::<AssetManager>().unwrap();
+                let asset_manager = asset_manager(&mut self.world);
                 asset_manager.register_asset_type::<T>()
             };
 
@@ -101,10 +285,24 @@ impl AssetExtension for Engine {
 
     fn add_asset_loader<T: AssetLoader>(&mut self, loader: T) -> &mut Self {
         {
-            let mut asset_manager = self.world.get_mut_resource::<AssetManager>().unwrap();
+            let mut asset_manager = asset_manager(&mut self.world);
             asset_manager.register_loader(loader);
         }
 
         self
     }
+
+    fn add_embedded_asset<P: AsRef<Path>>(&mut self, path: P, data: &'static [u8]) -> &mut Self {
+        asset_manager(&mut self.world).add_embedded_asset(path, data);
+
+        self
+    }
+
+    fn add_asset_collection<C: AssetCollection>(&mut self) -> &mut Self {
+        // the asset sources are configured only when the asset module is initialized
+        self.add_startup_system(load_asset_collection::<C>);
+        self.add_system_into_stage(check_asset_collection::<C>, Stage::PreUpdate);
+
+        self
+    }
 }
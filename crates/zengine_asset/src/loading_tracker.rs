@@ -0,0 +1,166 @@
+use rustc_hash::FxHashMap;
+use zengine_ecs::system::{Res, ResMut};
+use zengine_macro::Resource;
+
+use crate::{AssetManager, HandleId, LoadState};
+
+/// A [Resource](zengine_ecs::Resource) that tracks the loading progress of a set of assets,
+/// useful to show a loading screen
+///
+/// The assets can be of any type and their dependencies are taken in consideration,
+/// see [AssetManager::get_recursive_load_state].
+/// The tracker is updated at the start of each frame by the [AssetModule](crate::AssetModule).
+///
+/// An asset is not tracked anymore when all its strong handles have been dropped
+/// and it has been unloaded, so a canceled load doesn't keep the tracker waiting.
+///
+/// # Example
+/// ```
+/// use zengine_asset::{AssetManager, Handle, LoadingTracker};
+/// use zengine_ecs::system::{Res, ResMut};
+/// use zengine_macro::Asset;
+///
+/// # #[derive(Asset, Debug)]
+/// # struct Image;
+/// fn start_loading(mut asset_manager: ResMut<AssetManager>, mut tracker: ResMut<LoadingTracker>) {
+///     let image: Handle<Image> = asset_manager.load("image.png");
+///     tracker.track(&image);
+/// }
+///
+/// fn loading_screen(tracker: Res<LoadingTracker>) {
+///     println!("Loading {:.0}%", tracker.progress() * 100.);
+///     if tracker.is_done() {
+///         println!("{} assets failed to load", tracker.failed());
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct LoadingTracker {
+    assets: FxHashMap<HandleId, LoadState>,
+}
+
+impl LoadingTracker {
+    /// Starts tracking the asset pointed by the given handle
+    pub fn track<H: Into<HandleId>>(&mut self, handle: H) {
+        self.assets
+            .entry(handle.into())
+            .or_insert(LoadState::Loading);
+    }
+
+    /// Starts tracking all the assets pointed by the given handles
+    pub fn track_all<H: Into<HandleId>, I: IntoIterator<Item = H>>(&mut self, handles: I) {
+        for handle in handles {
+            self.track(handle);
+        }
+    }
+
+    /// Stops tracking all the assets
+    pub fn clear(&mut self) {
+        self.assets.clear();
+    }
+
+    /// Returns the number of tracked assets that have been loaded
+    pub fn loaded(&self) -> usize {
+        self.count(LoadState::Loaded)
+    }
+
+    /// Returns the number of tracked assets that failed to load
+    pub fn failed(&self) -> usize {
+        self.count(LoadState::Failed)
+    }
+
+    /// Returns the number of tracked assets
+    pub fn total(&self) -> usize {
+        self.assets.len()
+    }
+
+    /// Returns `true` when all the tracked assets have been loaded or failed to load
+    pub fn is_done(&self) -> bool {
+        self.loaded() + self.failed() == self.total()
+    }
+
+    /// Returns the fraction of the tracked assets that have been loaded or failed to load,
+    /// from `0.0` to `1.0`
+    pub fn progress(&self) -> f32 {
+        if self.assets.is_empty() {
+            return 1.;
+        }
+
+        (self.loaded() + self.failed()) as f32 / self.total() as f32
+    }
+
+    fn count(&self, state: LoadState) -> usize {
+        self.assets.values().filter(|s| **s == state).count()
+    }
+
+    fn update(&mut self, asset_manager: &AssetManager) {
+        self.assets.retain(|id, state| {
+            *state = asset_manager.get_recursive_load_state(*id);
+            *state != LoadState::NotLoaded || asset_manager.get_handle_ref_count(*id) > 0
+        });
+    }
+}
+
+pub(crate) fn update_loading_tracker(
+    mut tracker: ResMut<LoadingTracker>,
+    asset_manager: Res<AssetManager>,
+) {
+    tracker.update(&asset_manager);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use crate::{
+        io::MemoryAssetIo,
+        test_utils::{level_engine, Level},
+        AssetManager, Handle, HandleId, LoadingTracker,
+    };
+
+    #[test]
+    fn track_loads() {
+        let mut engine = level_engine(
+            MemoryAssetIo::default()
+                .with_asset("1.level", [1].as_slice())
+                .with_asset("2.level", [2].as_slice()),
+        );
+        engine.startup();
+
+        let handles: Vec<Handle<Level>> = {
+            let mut asset_manager = engine.world.get_mut_resource::<AssetManager>().unwrap();
+            ["1.level", "2.level", "missing.level"]
+                .into_iter()
+                .map(|path| asset_manager.load(path))
+                .collect()
+        };
+        {
+            let mut tracker = engine.world.get_mut_resource::<LoadingTracker>().unwrap();
+            tracker.track_all(&handles);
+            // never loaded and without strong handles
+            tracker.track(HandleId::new_from_u64::<Level>(42));
+            assert_eq!(tracker.total(), 4);
+            assert!(!tracker.is_done());
+        }
+
+        for _ in 0..200 {
+            engine.update();
+            if engine
+                .world
+                .get_resource::<LoadingTracker>()
+                .unwrap()
+                .is_done()
+            {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        let tracker = engine.world.get_resource::<LoadingTracker>().unwrap();
+        assert!(tracker.is_done());
+        assert_eq!(tracker.loaded(), 2);
+        assert_eq!(tracker.failed(), 1);
+        assert_eq!(tracker.total(), 3);
+        assert_eq!(tracker.progress(), 1.);
+    }
+}
@@ -0,0 +1,263 @@
+use std::{
+    borrow::Cow,
+    io::{self, Write},
+    ops::Range,
+    path::{Component, Path, PathBuf},
+};
+
+use rustc_hash::FxHashMap;
+
+const MAGIC: &[u8; 4] = b"ZPAK";
+const VERSION: u32 = 1;
+/// Size of an entry of the index with an empty path
+const ENTRY_MIN_SIZE: usize = 4 + 8 + 8;
+
+/// An archive that bundles many asset files in a single file
+///
+/// The pak format is made of a header with the magic `ZPAK` and the format version,
+/// an index with the relative path, the offset and the size of each file,
+/// and then the content of all the files.
+/// All the numbers are little endian.
+///
+/// A pak can be created with [write_pak] or with the `zengine_pak` command:
+/// ```sh
+/// cargo run -p zengine_asset --bin zengine_pak -- assets assets.pak
+/// ```
+#[derive(Debug)]
+pub struct Pak {
+    data: Cow<'static, [u8]>,
+    entries: FxHashMap<String, Range<usize>>,
+}
+
+impl Pak {
+    /// Reads a pak from its raw data, like a pak embedded with `include_bytes!`
+    pub fn from_bytes<D: Into<Cow<'static, [u8]>>>(data: D) -> io::Result<Self> {
+        let data = data.into();
+        let entries = read_index(&data)?;
+
+        Ok(Self { data, entries })
+    }
+
+    /// Reads a pak from a file
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_bytes(std::fs::read(path)?)
+    }
+
+    /// Returns the content of the file at the given relative path
+    pub fn get<P: AsRef<Path>>(&self, path: P) -> Option<&[u8]> {
+        self.entries
+            .get(&entry_name(path.as_ref())?)
+            .map(|range| &self.data[range.clone()])
+    }
+
+    /// Returns the relative paths of the files contained in the pak
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Returns the number of files contained in the pak
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the pak doesn't contain any file
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// The name of an entry is its relative path using `/` as separator
+pub(crate) fn entry_name(path: &Path) -> Option<String> {
+    let mut parts = Vec::default();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_str()?),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+
+    Some(parts.join("/"))
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Invalid pak: {}", message),
+    )
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        let bytes = self
+            .position
+            .checked_add(len)
+            .and_then(|end| self.data.get(self.position..end))
+            .ok_or_else(|| invalid_data("unexpected end of data"))?;
+        self.position += len;
+
+        Ok(bytes)
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> io::Result<u64> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+}
+
+fn read_index(data: &[u8]) -> io::Result<FxHashMap<String, Range<usize>>> {
+    let mut reader = Reader { data, position: 0 };
+    if reader.bytes(4)? != MAGIC {
+        return Err(invalid_data("wrong magic"));
+    }
+    let version = reader.u32()?;
+    if version != VERSION {
+        return Err(invalid_data(&format!("unsupported version {}", version)));
+    }
+
+    let count = reader.u32()? as usize;
+    if count > (data.len() - reader.position) / ENTRY_MIN_SIZE {
+        return Err(invalid_data(&format!("too many entries {}", count)));
+    }
+    let mut index = Vec::with_capacity(count);
+    for _ in 0..count {
+        let len = reader.u32()? as usize;
+        let name = std::str::from_utf8(reader.bytes(len)?)
+            .map_err(|_| invalid_data("path is not utf-8"))?
+            .to_owned();
+        let offset = reader.u64()? as usize;
+        let size = reader.u64()? as usize;
+        index.push((name, offset, size));
+    }
+
+    let data_start = reader.position;
+    index
+        .into_iter()
+        .map(|(name, offset, size)| {
+            let range = data_start
+                .checked_add(offset)
+                .and_then(|start| Some(start..start.checked_add(size)?))
+                .filter(|range| range.end <= data.len())
+                .ok_or_else(|| invalid_data(&format!("{} is out of bounds", name)))?;
+
+            Ok((name, range))
+        })
+        .collect()
+}
+
+/// Writes a pak containing the given files
+///
+/// Each file is a pair of its relative path inside the pak and its content
+pub fn write_pak<W, P, D>(output: &mut W, files: &[(P, D)]) -> io::Result<()>
+where
+    W: Write,
+    P: AsRef<Path>,
+    D: AsRef<[u8]>,
+{
+    output.write_all(MAGIC)?;
+    output.write_all(&VERSION.to_le_bytes())?;
+    output.write_all(&(files.len() as u32).to_le_bytes())?;
+
+    let mut offset = 0u64;
+    for (path, data) in files {
+        let name = entry_name(path.as_ref()).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{:?} is not a valid relative path", path.as_ref()),
+            )
+        })?;
+        let size = data.as_ref().len() as u64;
+
+        output.write_all(&(name.len() as u32).to_le_bytes())?;
+        output.write_all(name.as_bytes())?;
+        output.write_all(&offset.to_le_bytes())?;
+        output.write_all(&size.to_le_bytes())?;
+        offset += size;
+    }
+
+    for (_, data) in files {
+        output.write_all(data.as_ref())?;
+    }
+
+    Ok(())
+}
+
+/// Writes a pak containing all the files of a directory and of its subdirectories
+///
+/// It can be used from a build script to bundle the assets folder
+pub fn write_pak_from_dir<W: Write, P: AsRef<Path>>(output: &mut W, dir: P) -> io::Result<()> {
+    let dir = dir.as_ref();
+    let mut paths = Vec::default();
+    collect_files(dir, &mut paths)?;
+    // the same folder always produces the same pak
+    paths.sort();
+
+    let files = paths
+        .into_iter()
+        .map(|path| {
+            let data = std::fs::read(&path)?;
+            Ok((path.strip_prefix(dir).unwrap().to_path_buf(), data))
+        })
+        .collect::<io::Result<Vec<(PathBuf, Vec<u8>)>>>()?;
+
+    write_pak(output, &files)
+}
+
+fn collect_files(dir: &Path, paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, paths)?;
+        } else {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_pak, write_pak_from_dir, Pak, MAGIC, VERSION};
+
+    #[test]
+    fn write_and_read() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("sprites")).unwrap();
+        std::fs::write(dir.path().join("sprites/player.png"), [1, 2, 3]).unwrap();
+        std::fs::write(dir.path().join("music.ogg"), [4, 5]).unwrap();
+
+        let mut data = Vec::default();
+        write_pak_from_dir(&mut data, dir.path()).unwrap();
+        let pak = Pak::from_bytes(data).unwrap();
+
+        assert_eq!(pak.len(), 2);
+        assert_eq!(pak.get("sprites/player.png"), Some([1, 2, 3].as_slice()));
+        assert_eq!(pak.get("./music.ogg"), Some([4, 5].as_slice()));
+        assert_eq!(pak.get("missing.png"), None);
+        assert_eq!(pak.get("../music.ogg"), None);
+    }
+
+    #[test]
+    fn invalid_pak() {
+        assert!(Pak::from_bytes(b"NOPE".as_slice()).is_err());
+
+        let mut data = Vec::default();
+        write_pak(&mut data, &[("file.txt", [1, 2, 3])]).unwrap();
+        data.truncate(data.len() - 1);
+        assert!(Pak::from_bytes(data).is_err());
+
+        let mut data = MAGIC.to_vec();
+        data.extend(VERSION.to_le_bytes());
+        data.extend(u32::MAX.to_le_bytes());
+        assert!(Pak::from_bytes(data).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+use zengine_engine::Engine;
+
+use crate::{
+    io::MemoryAssetIo, Asset, AssetExtension, AssetLoader, AssetManager, AssetModule,
+    LoaderContext, LoaderError,
+};
+
+#[derive(Debug)]
+pub(crate) struct Level;
+impl Asset for Level {
+    fn next_counter() -> u64
+    where
+        Self: Sized,
+    {
+        0
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct LevelLoader;
+impl AssetLoader for LevelLoader {
+    fn extension(&self) -> &[&str] {
+        &["level"]
+    }
+
+    fn load(&self, _data: Vec<u8>, context: &mut LoaderContext) -> Result<(), LoaderError> {
+        context.set_asset(Level);
+        Ok(())
+    }
+}
+
+/// Returns an [Engine] loading the [Level] assets from memory
+pub(crate) fn level_engine(memory: MemoryAssetIo) -> Engine {
+    let mut engine = Engine::default();
+    engine.world.create_resource(AssetManager::new(memory));
+    engine
+        .add_module(AssetModule::default())
+        .add_asset::<Level>()
+        .add_asset_loader(LevelLoader);
+
+    engine
+}
@@ -5,9 +5,11 @@ use std::fmt::Debug;
 use std::io::Cursor;
 use std::sync::RwLock;
 use zengine_asset::Asset;
-use zengine_asset::{AssetExtension, AssetLoader, Assets, Handle, HandleId};
+use zengine_asset::{
+    AssetExtension, AssetLoader, AssetModule, Assets, Handle, HandleId, LoaderError,
+};
 use zengine_ecs::system::{Local, Res, ResMut, UnsendableRes};
-use zengine_engine::{Module, Stage};
+use zengine_engine::{Module, ModuleId, Stage};
 use zengine_macro::{Asset, Resource, UnsendableResource};
 
 /// Adds audio support to the engine
@@ -28,6 +30,10 @@ impl Module for AudioModule {
         #[cfg(target_os = "android")]
         engine.add_system_into_stage(handle_resume_suspended, Stage::PreUpdate);
     }
+
+    fn dependencies(&self) -> Vec<ModuleId> {
+        vec![ModuleId::of::<AssetModule>()]
+    }
 }
 
 #[derive(Debug)]
@@ -37,8 +43,14 @@ impl AssetLoader for AudioLoader {
         &["ogg", "wav", "flac"]
     }
 
-    fn load(&self, data: Vec<u8>, context: &mut zengine_asset::LoaderContext) {
+    fn load(
+        &self,
+        data: Vec<u8>,
+        context: &mut zengine_asset::LoaderContext,
+    ) -> Result<(), LoaderError> {
         context.set_asset(Audio { data });
+
+        Ok(())
     }
 }
 
@@ -0,0 +1,242 @@
+use std::f32::consts::PI;
+
+/// Easing curves used to shape the progress of a [Tween](crate::Tween)
+///
+/// Each curve maps a linear progress from `0.0` to `1.0` into the eased progress.
+/// The `In` curves start slowly, the `Out` curves end slowly
+/// and the `InOut` curves do both
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum EaseFunction {
+    /// Constant speed
+    #[default]
+    Linear,
+    QuadraticIn,
+    QuadraticOut,
+    QuadraticInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuarticIn,
+    QuarticOut,
+    QuarticInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExponentialIn,
+    ExponentialOut,
+    ExponentialInOut,
+    CircularIn,
+    CircularOut,
+    CircularInOut,
+    /// Moves slightly backward before starting
+    BackIn,
+    /// Overshoots the end before settling
+    BackOut,
+    BackInOut,
+    /// Oscillates around the start like a spring
+    ElasticIn,
+    /// Oscillates around the end like a spring
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    /// Bounces on the end like a dropped ball
+    BounceOut,
+    BounceInOut,
+    /// Jumps between the given number of discrete steps
+    Steps(u32),
+}
+
+impl EaseFunction {
+    /// Returns the eased progress for the given linear progress.
+    ///
+    /// The progress is clamped between `0.0` and `1.0`
+    pub fn sample(&self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            EaseFunction::Linear => t,
+            EaseFunction::QuadraticIn => t * t,
+            EaseFunction::QuadraticOut => 1. - (1. - t).powi(2),
+            EaseFunction::QuadraticInOut => {
+                if t < 0.5 {
+                    2. * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(2) / 2.
+                }
+            }
+            EaseFunction::CubicIn => t.powi(3),
+            EaseFunction::CubicOut => 1. - (1. - t).powi(3),
+            EaseFunction::CubicInOut => {
+                if t < 0.5 {
+                    4. * t.powi(3)
+                } else {
+                    1. - (-2. * t + 2.).powi(3) / 2.
+                }
+            }
+            EaseFunction::QuarticIn => t.powi(4),
+            EaseFunction::QuarticOut => 1. - (1. - t).powi(4),
+            EaseFunction::QuarticInOut => {
+                if t < 0.5 {
+                    8. * t.powi(4)
+                } else {
+                    1. - (-2. * t + 2.).powi(4) / 2.
+                }
+            }
+            EaseFunction::SineIn => 1. - (t * PI / 2.).cos(),
+            EaseFunction::SineOut => (t * PI / 2.).sin(),
+            EaseFunction::SineInOut => -((PI * t).cos() - 1.) / 2.,
+            EaseFunction::ExponentialIn => {
+                if t == 0. {
+                    0.
+                } else {
+                    2f32.powf(10. * t - 10.)
+                }
+            }
+            EaseFunction::ExponentialOut => {
+                if t == 1. {
+                    1.
+                } else {
+                    1. - 2f32.powf(-10. * t)
+                }
+            }
+            EaseFunction::ExponentialInOut => {
+                if t == 0. || t == 1. {
+                    t
+                } else if t < 0.5 {
+                    2f32.powf(20. * t - 10.) / 2.
+                } else {
+                    (2. - 2f32.powf(-20. * t + 10.)) / 2.
+                }
+            }
+            EaseFunction::CircularIn => 1. - (1. - t * t).sqrt(),
+            EaseFunction::CircularOut => (1. - (t - 1.).powi(2)).sqrt(),
+            EaseFunction::CircularInOut => {
+                if t < 0.5 {
+                    (1. - (1. - (2. * t).powi(2)).sqrt()) / 2.
+                } else {
+                    ((1. - (-2. * t + 2.).powi(2)).sqrt() + 1.) / 2.
+                }
+            }
+            EaseFunction::BackIn => {
+                const C1: f32 = 1.70158;
+                (C1 + 1.) * t.powi(3) - C1 * t * t
+            }
+            EaseFunction::BackOut => {
+                const C1: f32 = 1.70158;
+                1. + (C1 + 1.) * (t - 1.).powi(3) + C1 * (t - 1.).powi(2)
+            }
+            EaseFunction::BackInOut => {
+                const C2: f32 = 1.70158 * 1.525;
+                if t < 0.5 {
+                    (2. * t).powi(2) * ((C2 + 1.) * 2. * t - C2) / 2.
+                } else {
+                    ((2. * t - 2.).powi(2) * ((C2 + 1.) * (2. * t - 2.) + C2) + 2.) / 2.
+                }
+            }
+            EaseFunction::ElasticIn => {
+                const C4: f32 = 2. * PI / 3.;
+                if t == 0. || t == 1. {
+                    t
+                } else {
+                    -(2f32.powf(10. * t - 10.)) * ((10. * t - 10.75) * C4).sin()
+                }
+            }
+            EaseFunction::ElasticOut => {
+                const C4: f32 = 2. * PI / 3.;
+                if t == 0. || t == 1. {
+                    t
+                } else {
+                    2f32.powf(-10. * t) * ((10. * t - 0.75) * C4).sin() + 1.
+                }
+            }
+            EaseFunction::ElasticInOut => {
+                const C5: f32 = 2. * PI / 4.5;
+                if t == 0. || t == 1. {
+                    t
+                } else if t < 0.5 {
+                    -(2f32.powf(20. * t - 10.) * ((20. * t - 11.125) * C5).sin()) / 2.
+                } else {
+                    2f32.powf(-20. * t + 10.) * ((20. * t - 11.125) * C5).sin() / 2. + 1.
+                }
+            }
+            EaseFunction::BounceIn => 1. - bounce_out(1. - t),
+            EaseFunction::BounceOut => bounce_out(t),
+            EaseFunction::BounceInOut => {
+                if t < 0.5 {
+                    (1. - bounce_out(1. - 2. * t)) / 2.
+                } else {
+                    (1. + bounce_out(2. * t - 1.)) / 2.
+                }
+            }
+            EaseFunction::Steps(steps) => {
+                let steps = (*steps).max(1) as f32;
+                (t * steps).floor() / steps
+            }
+        }
+    }
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+
+    if t < 1. / D1 {
+        N1 * t * t
+    } else if t < 2. / D1 {
+        let t = t - 1.5 / D1;
+        N1 * t * t + 0.75
+    } else if t < 2.5 / D1 {
+        let t = t - 2.25 / D1;
+        N1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D1;
+        N1 * t * t + 0.984375
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EaseFunction;
+
+    #[test]
+    fn curves_start_at_zero_and_end_at_one() {
+        let curves = [
+            EaseFunction::Linear,
+            EaseFunction::QuadraticIn,
+            EaseFunction::QuadraticOut,
+            EaseFunction::QuadraticInOut,
+            EaseFunction::CubicIn,
+            EaseFunction::CubicOut,
+            EaseFunction::CubicInOut,
+            EaseFunction::QuarticIn,
+            EaseFunction::QuarticOut,
+            EaseFunction::QuarticInOut,
+            EaseFunction::SineIn,
+            EaseFunction::SineOut,
+            EaseFunction::SineInOut,
+            EaseFunction::ExponentialIn,
+            EaseFunction::ExponentialOut,
+            EaseFunction::ExponentialInOut,
+            EaseFunction::CircularIn,
+            EaseFunction::CircularOut,
+            EaseFunction::CircularInOut,
+            EaseFunction::BackIn,
+            EaseFunction::BackOut,
+            EaseFunction::BackInOut,
+            EaseFunction::ElasticIn,
+            EaseFunction::ElasticOut,
+            EaseFunction::ElasticInOut,
+            EaseFunction::BounceIn,
+            EaseFunction::BounceOut,
+            EaseFunction::BounceInOut,
+            EaseFunction::Steps(4),
+        ];
+
+        for curve in curves {
+            assert!(curve.sample(0.).abs() < 1e-5, "{:?} at 0", curve);
+            assert!((curve.sample(1.) - 1.).abs() < 1e-5, "{:?} at 1", curve);
+        }
+
+        assert_eq!(EaseFunction::QuadraticIn.sample(0.5), 0.25);
+        assert_eq!(EaseFunction::Steps(4).sample(0.6), 0.5);
+    }
+}
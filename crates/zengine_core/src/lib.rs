@@ -1,7 +1,11 @@
+mod ease;
 mod time;
 mod timer;
 mod transform;
+mod tween;
 
+pub use ease::*;
 pub use time::*;
 pub use timer::*;
 pub use transform::*;
+pub use tween::*;
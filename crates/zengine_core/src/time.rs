@@ -1,12 +1,17 @@
 use instant::Instant;
 use log::trace;
 use std::thread::sleep;
-use zengine_ecs::system::{EventStream, Local, ResMut};
-use zengine_engine::{EngineEvent, Module, Stage};
+use zengine_ecs::{
+    system::{EventStream, Res, ResMut},
+    World,
+};
+use zengine_engine::{EngineEvent, Module, ShouldRun, SimulatedTimeStep, Stage};
 
 use std::time::Duration;
 use zengine_macro::Resource;
 
+use crate::tick_timers;
+
 /// A struct that rappresent a frame limiter
 #[derive(Debug)]
 pub struct FrameLimiter {
@@ -31,74 +36,381 @@ impl Default for FrameLimiter {
     }
 }
 
-/// A [Resource](zengine_ecs::Resource) that contains the time elapsed between frames
+/// A [Resource](zengine_ecs::Resource) that contains the virtual time elapsed between frames
+///
+/// The virtual time can be paused and scaled to implement pause menus,
+/// slow motion or fast-forward. The [FixedTime] and the [Timer](crate::Timer)s
+/// follow the virtual time. Use [RealTime] to read the unscaled time
+///
+/// # Example
+/// ```
+/// use zengine_core::Time;
+/// use zengine_ecs::system::ResMut;
+///
+/// fn slow_motion_system(mut time: ResMut<Time>) {
+///     time.set_relative_speed(0.5);
+/// }
+/// ```
 #[derive(Resource, Debug)]
 pub struct Time {
     /// Delta time between each frame
     delta: Duration,
+    elapsed: Duration,
+    paused: bool,
+    relative_speed: f64,
 }
 
 impl Default for Time {
     fn default() -> Self {
         Time {
             delta: Duration::from_secs(1),
+            elapsed: Duration::ZERO,
+            paused: false,
+            relative_speed: 1.,
         }
     }
 }
 
 impl Time {
     /// Returns how much time has advanced since the cycle, as a [`Duration`].
+    ///
+    /// It's zero when the time is paused
     pub fn delta(&self) -> Duration {
         self.delta
     }
+
+    /// Returns the virtual time elapsed since the startup
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Stops the virtual time
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes the virtual time
+    pub fn unpause(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns `true` if the virtual time is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns the speed of the virtual time compared to the real time
+    pub fn relative_speed(&self) -> f64 {
+        self.relative_speed
+    }
+
+    /// Sets the speed of the virtual time compared to the real time.
+    /// `2.0` means that the virtual time runs two times faster than the real time
+    ///
+    /// # Panics
+    /// Panics if the speed is negative or not finite
+    pub fn set_relative_speed(&mut self, relative_speed: f64) {
+        if !relative_speed.is_finite() || relative_speed < 0. {
+            panic!("Invalid relative speed {}", relative_speed);
+        }
+        self.relative_speed = relative_speed;
+    }
+
+    /// Advances the virtual time using the real time elapsed during the last frame
+    pub fn advance(&mut self, real_delta: Duration) {
+        self.delta = if self.paused {
+            Duration::ZERO
+        } else {
+            real_delta.mul_f64(self.relative_speed)
+        };
+        self.elapsed += self.delta;
+    }
 }
 
-#[derive(Debug)]
-struct SystemInstant(Instant);
-impl Default for SystemInstant {
+/// A [Resource](zengine_ecs::Resource) that contains the real time elapsed between frames
+///
+/// Unlike [Time], the real time can't be paused or scaled
+#[derive(Resource, Debug)]
+pub struct RealTime {
+    delta: Duration,
+    elapsed: Duration,
+    last_update: Instant,
+}
+
+impl Default for RealTime {
     fn default() -> Self {
-        SystemInstant(Instant::now())
+        RealTime {
+            delta: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            last_update: Instant::now(),
+        }
+    }
+}
+
+impl RealTime {
+    /// Returns the real time elapsed during the last frame
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    /// Returns the real time elapsed since the startup
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn advance(&mut self, delta: Duration) {
+        self.delta = delta;
+        self.elapsed += delta;
+    }
+}
+
+/// Maximum frame time added to the [FixedTime] accumulator.
+/// Prevents an endless catch up after a long frame
+const MAX_FIXED_ACCUMULATION: Duration = Duration::from_millis(250);
+
+/// A [Resource](zengine_ecs::Resource) that drives the [FixedUpdate Stage](Stage::FixedUpdate)
+///
+/// Each frame the elapsed time is added to an accumulator and the fixed stage
+/// runs one time for each step contained in the accumulator.
+/// The default rate is 60 steps per second
+///
+/// # Example
+/// ```
+/// use zengine_core::{FixedTime, TimeModule};
+/// use zengine_ecs::system::Res;
+/// use zengine_engine::{Engine, Stage};
+///
+/// fn physics_system(fixed_time: Res<FixedTime>) {
+///     println!("step: {:?}", fixed_time.delta());
+/// }
+///
+/// Engine::default()
+///     .add_module(TimeModule(None))
+///     .add_system_into_stage(physics_system, Stage::FixedUpdate);
+/// ```
+#[derive(Resource, Debug)]
+pub struct FixedTime {
+    step: Duration,
+    accumulator: Duration,
+}
+
+impl Default for FixedTime {
+    fn default() -> Self {
+        FixedTime::from_hz(60.)
+    }
+}
+
+impl FixedTime {
+    /// Creates a FixedTime with the given step duration
+    pub fn new(step: Duration) -> Self {
+        FixedTime {
+            step,
+            accumulator: Duration::ZERO,
+        }
+    }
+
+    /// Creates a FixedTime that runs the given number of steps per second
+    pub fn from_hz(hz: f64) -> Self {
+        FixedTime::new(Duration::from_secs_f64(1. / hz))
+    }
+
+    /// Returns the fixed time advanced by each step, as a [`Duration`].
+    pub fn delta(&self) -> Duration {
+        self.step
+    }
+
+    /// Returns the time accumulated and not yet consumed by a step
+    pub fn accumulated(&self) -> Duration {
+        self.accumulator
+    }
+
+    /// Returns how far the accumulator is between the last step and the next one.
+    /// Should be used to interpolate the rendering between two steps
+    pub fn alpha(&self) -> f32 {
+        self.accumulator.as_secs_f32() / self.step.as_secs_f32()
+    }
+
+    /// Adds the frame time to the accumulator
+    pub fn accumulate(&mut self, delta: Duration) {
+        self.accumulator += delta.min(MAX_FIXED_ACCUMULATION);
+    }
+
+    /// Consumes a step from the accumulator.
+    /// Returns `true` if the accumulator contained a step
+    pub fn expend(&mut self) -> bool {
+        if self.accumulator >= self.step {
+            self.accumulator -= self.step;
+            true
+        } else {
+            false
+        }
     }
 }
 
 /// Adds timing suport to the engine
 ///
-/// This module add a [Time] resource that measure
-/// the time passed between each frame
+/// This module add a [RealTime] resource that measure
+/// the time passed between each frame, a [Time] resource with the virtual time
+/// and a [FixedTime] resource that drives the [FixedUpdate Stage](Stage::FixedUpdate).
+/// It also ticks all the [Timer](crate::Timer) and [Stopwatch](crate::Stopwatch) components
+///
+/// When a [SimulatedTimeStep] resource is present the frame time
+/// is the simulated step and the frame limiter is ignored
 pub struct TimeModule(
     /// Optional [FrameLimiter] to add to the engine
     pub Option<FrameLimiter>,
 );
 impl Module for TimeModule {
     fn init(self, engine: &mut zengine_engine::Engine) {
-        engine.add_system_into_stage(timing_system(self.0), Stage::PreUpdate);
+        if engine.world.get_resource::<FixedTime>().is_none() {
+            engine.world.create_resource(FixedTime::default());
+        }
+
+        engine
+            .add_system_into_stage(timing_system(self.0), Stage::PreUpdate)
+            .add_system_into_stage(virtual_time_system, Stage::PreUpdate)
+            .add_system_into_stage(tick_timers, Stage::PreUpdate)
+            .set_stage_run_criteria(Stage::FixedUpdate, fixed_timestep_criteria);
+    }
+}
+
+fn fixed_timestep_criteria(world: &mut World) -> ShouldRun {
+    let expended = world
+        .get_mut_resource::<FixedTime>()
+        .map(|mut fixed_time| fixed_time.expend())
+        .unwrap_or(false);
+
+    if expended {
+        ShouldRun::YesAndCheckAgain
+    } else {
+        ShouldRun::No
     }
 }
 
+type SimulatedStep<'a> = Option<Res<'a, SimulatedTimeStep>>;
+
 fn timing_system(
     limiter: Option<FrameLimiter>,
-) -> impl Fn(EventStream<EngineEvent>, ResMut<Time>, Local<SystemInstant>) {
+) -> impl Fn(EventStream<EngineEvent>, ResMut<RealTime>, SimulatedStep) {
     move |engine_event: EventStream<EngineEvent>,
-          mut time: ResMut<Time>,
-          last_call: Local<SystemInstant>| {
+          mut real_time: ResMut<RealTime>,
+          simulated_step: SimulatedStep| {
+        if let Some(simulated_step) = simulated_step {
+            real_time.advance(simulated_step.0);
+
+            trace!("real time: {:?}", real_time);
+            return;
+        }
+
         if engine_event.read().last() == Some(&EngineEvent::Resumed) {
-            last_call.0 = Instant::now();
+            real_time.last_update = Instant::now();
         }
 
         let mut finish = Instant::now();
-        let mut elapsed = finish - last_call.0;
+        let mut elapsed = finish - real_time.last_update;
 
         if let Some(limiter) = &limiter {
             if elapsed < limiter.frame_duration {
                 sleep(limiter.frame_duration - elapsed);
                 finish = Instant::now();
-                elapsed += finish - last_call.0;
+                elapsed += finish - real_time.last_update;
             }
         }
 
-        time.delta = elapsed;
-        last_call.0 = finish;
+        real_time.advance(elapsed);
+        real_time.last_update = finish;
+
+        trace!("real time: {:?}", real_time);
+    }
+}
+
+fn virtual_time_system(
+    real_time: Res<RealTime>,
+    mut time: ResMut<Time>,
+    mut fixed_time: ResMut<FixedTime>,
+) {
+    time.advance(real_time.delta());
+    fixed_time.accumulate(time.delta());
+
+    trace!("time: {:?}", time);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use zengine_ecs::system::{Res, ResMut};
+    use zengine_engine::{Engine, HeadlessRunner, Stage};
+    use zengine_macro::Resource;
+
+    use crate::{FixedTime, RealTime, Time, TimeModule};
+
+    #[derive(Resource, Debug, Default)]
+    struct Steps(u32);
+
+    fn step_system(mut steps: ResMut<Steps>) {
+        steps.0 += 1;
+    }
+
+    fn check_delta(time: Res<Time>) {
+        assert_eq!(time.delta(), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn simulated_time_step() {
+        let mut engine = Engine::default();
+        engine
+            .add_module(TimeModule(None))
+            .add_system(check_delta)
+            .add_system_into_stage(step_system, Stage::FixedUpdate);
+        engine
+            .world
+            .create_resource(FixedTime::new(Duration::from_millis(20)));
+
+        HeadlessRunner::frames(10)
+            .with_time_step(Duration::from_millis(50))
+            .run(&mut engine);
+
+        assert_eq!(engine.world.get_resource::<Steps>().unwrap().0, 25);
+    }
+
+    #[test]
+    fn scaled_and_paused_virtual_time() {
+        let mut engine = Engine::default();
+        engine
+            .add_module(TimeModule(None))
+            .add_system_into_stage(step_system, Stage::FixedUpdate);
+        engine
+            .world
+            .create_resource(FixedTime::new(Duration::from_millis(20)));
+        engine.world.create_resource(Time::default());
+        engine
+            .world
+            .get_mut_resource::<Time>()
+            .unwrap()
+            .set_relative_speed(2.);
+
+        let runner = HeadlessRunner::frames(5).with_time_step(Duration::from_millis(50));
+        runner.run(&mut engine);
+
+        assert_eq!(
+            engine.world.get_resource::<Time>().unwrap().elapsed(),
+            Duration::from_millis(500)
+        );
+        assert_eq!(engine.world.get_resource::<Steps>().unwrap().0, 25);
+
+        engine.world.get_mut_resource::<Time>().unwrap().pause();
+        for _ in 0..5 {
+            engine.update();
+        }
 
-        trace!("time: {:?}", time);
+        let time = engine.world.get_resource::<Time>().unwrap();
+        assert_eq!(time.delta(), Duration::ZERO);
+        assert_eq!(time.elapsed(), Duration::from_millis(500));
+        assert_eq!(
+            engine.world.get_resource::<RealTime>().unwrap().elapsed(),
+            Duration::from_millis(500)
+        );
+        assert_eq!(engine.world.get_resource::<Steps>().unwrap().0, 25);
     }
 }
@@ -1,34 +1,319 @@
 use instant::Duration;
+use zengine_ecs::{
+    query::{Query, QueryIterMut},
+    system::Res,
+};
 use zengine_macro::{Component, Resource};
 
 use crate::Time;
 
+/// Tracks the elapsed time. Unlike the [Timer] it doesn't have a duration
+#[derive(Resource, Component, Debug, Default, Clone)]
+pub struct Stopwatch {
+    elapsed: Duration,
+    paused: bool,
+}
+
+impl Stopwatch {
+    /// Creates a new stopwatch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advance the stopwatch.
+    pub fn tick(&mut self, time: &Time) -> &Self {
+        self.advance(time.delta())
+    }
+
+    /// Advance the stopwatch by the given amount of time.
+    pub fn advance(&mut self, delta: Duration) -> &Self {
+        if !self.paused {
+            self.elapsed += delta;
+        }
+        self
+    }
+
+    /// Returns the elapsed time
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the elapsed time in seconds
+    pub fn elapsed_secs(&self) -> f32 {
+        self.elapsed.as_secs_f32()
+    }
+
+    /// Sets the elapsed time
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed;
+    }
+
+    /// Stops the stopwatch, the time advanced while paused is ignored
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes the stopwatch
+    pub fn unpause(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns `true` if the stopwatch is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Resets the elapsed time to zero
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+    }
+}
+
+/// Defines what a [Timer] does when it reaches its duration
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TimerMode {
+    /// The timer stays in the finished state
+    #[default]
+    Once,
+    /// The timer restarts from zero keeping the exceeding time
+    Repeating,
+}
+
 /// Tracks elapsed time. Enters the finished state once `duration` is reached.
-#[derive(Resource, Component, Debug)]
+///
+/// A [Repeating](TimerMode::Repeating) timer restarts each time it reaches its duration.
+/// The Timer components are ticked automatically by the [TimeModule](crate::TimeModule)
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use zengine_core::{Timer, TimerMode};
+///
+/// let mut timer = Timer::new(Duration::from_secs(1)).with_mode(TimerMode::Repeating);
+/// timer.advance(Duration::from_millis(2500));
+///
+/// assert!(timer.just_finished());
+/// assert_eq!(timer.times_finished_this_tick(), 2);
+/// assert_eq!(timer.elapsed(), Duration::from_millis(500));
+/// ```
+#[derive(Resource, Component, Debug, Clone)]
 pub struct Timer {
-    elapsed: Duration,
+    stopwatch: Stopwatch,
     duration: Duration,
+    mode: TimerMode,
     finished: bool,
+    times_finished_this_tick: u32,
 }
 
 impl Timer {
     /// Creates a new timer with a given duration.
     pub fn new(duration: Duration) -> Self {
         Self {
+            stopwatch: Stopwatch::default(),
             duration,
-            elapsed: Duration::default(),
+            mode: TimerMode::default(),
             finished: false,
+            times_finished_this_tick: 0,
         }
     }
 
+    /// Sets the [TimerMode] of the timer
+    pub fn with_mode(mut self, mode: TimerMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
     /// Advance the timer.
-    pub fn tick(&mut self, time: &Time) {
-        self.elapsed += time.delta();
-        self.finished = self.elapsed >= self.duration;
+    pub fn tick(&mut self, time: &Time) -> &Self {
+        self.advance(time.delta())
+    }
+
+    /// Advance the timer by the given amount of time.
+    pub fn advance(&mut self, delta: Duration) -> &Self {
+        if self.is_paused() || (self.mode == TimerMode::Once && self.finished) {
+            self.times_finished_this_tick = 0;
+            if self.mode == TimerMode::Repeating {
+                self.finished = false;
+            }
+            return self;
+        }
+
+        self.stopwatch.advance(delta);
+        let elapsed = self.stopwatch.elapsed();
+        self.finished = elapsed >= self.duration;
+
+        if !self.finished {
+            self.times_finished_this_tick = 0;
+        } else if self.mode == TimerMode::Repeating && !self.duration.is_zero() {
+            let duration = self.duration.as_nanos();
+            let elapsed = elapsed.as_nanos();
+            self.times_finished_this_tick = (elapsed / duration).min(u32::MAX as u128) as u32;
+            self.stopwatch
+                .set_elapsed(Duration::from_nanos((elapsed % duration) as u64));
+        } else {
+            self.times_finished_this_tick = 1;
+            self.stopwatch.set_elapsed(self.duration);
+        }
+
+        self
     }
 
     /// Returns `true` if the timer has reached its duration
+    ///
+    /// A [Repeating](TimerMode::Repeating) timer is finished only in the tick
+    /// in which it reached its duration
     pub fn finished(&self) -> bool {
         self.finished
     }
+
+    /// Returns `true` if the timer has reached its duration during the last tick
+    pub fn just_finished(&self) -> bool {
+        self.times_finished_this_tick > 0
+    }
+
+    /// Returns how many times the timer has reached its duration during the last tick.
+    /// Only a [Repeating](TimerMode::Repeating) timer can finish more than one time
+    pub fn times_finished_this_tick(&self) -> u32 {
+        self.times_finished_this_tick
+    }
+
+    /// Returns the [TimerMode] of the timer
+    pub fn mode(&self) -> TimerMode {
+        self.mode
+    }
+
+    /// Returns the duration of the timer
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Sets the duration of the timer
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+
+    /// Returns the time elapsed since the timer start or the last repetition
+    pub fn elapsed(&self) -> Duration {
+        self.stopwatch.elapsed()
+    }
+
+    /// Returns the time left before the timer reaches its duration
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.elapsed())
+    }
+
+    /// Returns the progress of the timer from `0.0` to `1.0`
+    pub fn percent(&self) -> f32 {
+        if self.duration.is_zero() {
+            1.
+        } else {
+            (self.elapsed().as_secs_f32() / self.duration.as_secs_f32()).min(1.)
+        }
+    }
+
+    /// Returns the remaining progress of the timer from `1.0` to `0.0`
+    pub fn percent_left(&self) -> f32 {
+        1. - self.percent()
+    }
+
+    /// Stops the timer, the time advanced while paused is ignored
+    pub fn pause(&mut self) {
+        self.stopwatch.pause();
+    }
+
+    /// Resumes the timer
+    pub fn unpause(&mut self) {
+        self.stopwatch.unpause();
+    }
+
+    /// Returns `true` if the timer is paused
+    pub fn is_paused(&self) -> bool {
+        self.stopwatch.is_paused()
+    }
+
+    /// Restarts the timer from zero
+    pub fn reset(&mut self) {
+        self.stopwatch.reset();
+        self.finished = false;
+        self.times_finished_this_tick = 0;
+    }
+}
+
+/// Ticks all the [Timer] and [Stopwatch] components using the virtual [Time]
+///
+/// It's added to the [PreUpdate Stage](zengine_engine::Stage::PreUpdate)
+/// by the [TimeModule](crate::TimeModule)
+pub fn tick_timers(
+    time: Res<Time>,
+    mut timers: Query<(&mut Timer,)>,
+    mut stopwatches: Query<(&mut Stopwatch,)>,
+) {
+    for timer in timers.iter_mut() {
+        timer.tick(&time);
+    }
+    for stopwatch in stopwatches.iter_mut() {
+        stopwatch.tick(&time);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{Stopwatch, Timer, TimerMode};
+
+    #[test]
+    fn once_timer() {
+        let mut timer = Timer::new(Duration::from_secs(2));
+
+        timer.advance(Duration::from_millis(500));
+        assert!(!timer.finished());
+        assert_eq!(timer.percent(), 0.25);
+        assert_eq!(timer.remaining(), Duration::from_millis(1500));
+
+        timer.advance(Duration::from_secs(2));
+        assert!(timer.finished());
+        assert!(timer.just_finished());
+        assert_eq!(timer.elapsed(), Duration::from_secs(2));
+
+        timer.advance(Duration::from_secs(1));
+        assert!(timer.finished());
+        assert!(!timer.just_finished());
+
+        timer.reset();
+        assert!(!timer.finished());
+        assert_eq!(timer.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn repeating_timer() {
+        let mut timer = Timer::new(Duration::from_secs(1)).with_mode(TimerMode::Repeating);
+
+        timer.advance(Duration::from_millis(3200));
+        assert!(timer.finished());
+        assert_eq!(timer.times_finished_this_tick(), 3);
+        assert_eq!(timer.elapsed(), Duration::from_millis(200));
+
+        timer.advance(Duration::from_millis(100));
+        assert!(!timer.finished());
+        assert!(!timer.just_finished());
+    }
+
+    #[test]
+    fn paused_timer() {
+        let mut timer = Timer::new(Duration::from_secs(1));
+        timer.pause();
+        timer.advance(Duration::from_secs(2));
+        assert!(!timer.finished());
+
+        timer.unpause();
+        timer.advance(Duration::from_secs(2));
+        assert!(timer.finished());
+
+        let mut stopwatch = Stopwatch::new();
+        stopwatch.advance(Duration::from_secs(1));
+        stopwatch.pause();
+        stopwatch.advance(Duration::from_secs(1));
+        assert_eq!(stopwatch.elapsed(), Duration::from_secs(1));
+    }
 }
@@ -1,31 +1,47 @@
+use glam::{Mat3, Mat4, Quat, Vec3};
 use zengine_macro::Component;
 
 /// A [Component](zengine_ecs::Component) which describe the position of an entity.
 ///
 /// To place or move an entity, you should set its [`Transform`]
-#[derive(Component, Debug, Clone)]
+///
+/// # Example
+/// ```
+/// use glam::{Quat, Vec3};
+/// use zengine_core::Transform;
+///
+/// let mut transform = Transform::from_position(Vec3::new(1.0, 2.0, 0.0))
+///     .with_scale(Vec3::new(2.0, 0.5, 1.0));
+///
+/// transform.translate(Vec3::X);
+/// transform.rotate_z(std::f32::consts::FRAC_PI_2);
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
 pub struct Transform {
     /// Position of the entity. In 2d, the last value of the Vec3 is used for z-ordering
-    pub position: glam::Vec3,
+    pub position: Vec3,
     /// Rotation of the entity
-    pub rotation: glam::Vec3,
-    /// Scale of the entity
-    pub scale: f32,
+    pub rotation: Quat,
+    /// Scale of the entity along each axis
+    pub scale: Vec3,
 }
 
 impl Default for Transform {
     fn default() -> Self {
-        Transform {
-            position: glam::Vec3::new(0.0, 0.0, 0.0),
-            rotation: glam::Vec3::new(0.0, 0.0, 0.0),
-            scale: 1.0,
-        }
+        Transform::IDENTITY
     }
 }
 
 impl Transform {
+    /// A transform without translation, rotation and scale
+    pub const IDENTITY: Self = Transform {
+        position: Vec3::ZERO,
+        rotation: Quat::IDENTITY,
+        scale: Vec3::ONE,
+    };
+
     /// Creates a new trasform from a position, a rotation and a scale
-    pub fn new(position: glam::Vec3, rotation: glam::Vec3, scale: f32) -> Self {
+    pub fn new(position: Vec3, rotation: Quat, scale: Vec3) -> Self {
         Transform {
             position,
             rotation,
@@ -33,16 +49,156 @@ impl Transform {
         }
     }
 
+    /// Creates a new transform placed at the given position
+    pub fn from_position(position: Vec3) -> Self {
+        Transform {
+            position,
+            ..Transform::IDENTITY
+        }
+    }
+
+    /// Returns the transform with the given rotation
+    pub fn with_rotation(mut self, rotation: Quat) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Returns the transform with the given rotation expressed
+    /// as euler angles in degrees
+    pub fn with_euler_degrees(mut self, angles: Vec3) -> Self {
+        self.set_euler_degrees(angles);
+        self
+    }
+
+    /// Returns the transform with the given scale
+    pub fn with_scale(mut self, scale: Vec3) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Returns the rotation as euler angles in degrees.
+    /// The rotations are applied in the Z, Y, X order
+    pub fn euler_degrees(&self) -> Vec3 {
+        let (x, y, z) = self.rotation.to_euler(glam::EulerRot::XYZ);
+        Vec3::new(x.to_degrees(), y.to_degrees(), z.to_degrees())
+    }
+
+    /// Sets the rotation from euler angles in degrees.
+    /// The rotations are applied in the Z, Y, X order
+    pub fn set_euler_degrees(&mut self, angles: Vec3) {
+        self.rotation = Quat::from_rotation_x(angles.x.to_radians())
+            * Quat::from_rotation_y(angles.y.to_radians())
+            * Quat::from_rotation_z(angles.z.to_radians());
+    }
+
+    /// Moves the transform by the given amount
+    pub fn translate(&mut self, delta: Vec3) {
+        self.position += delta;
+    }
+
+    /// Applies the given rotation after the current one
+    pub fn rotate(&mut self, rotation: Quat) {
+        self.rotation = rotation * self.rotation;
+    }
+
+    /// Rotates the transform around the Z axis by the given angle in radians
+    pub fn rotate_z(&mut self, angle: f32) {
+        self.rotate(Quat::from_rotation_z(angle));
+    }
+
+    /// Rotates the transform so that its local Z axis points to the target
+    /// and its local Y axis points as close as possible to the `up` direction
+    ///
+    /// The rotation is not changed if the target is the transform position
+    /// or if the target direction is parallel to `up`
+    pub fn look_at(&mut self, target: Vec3, up: Vec3) {
+        let forward = (target - self.position).normalize_or_zero();
+        let right = up.cross(forward).normalize_or_zero();
+        if forward == Vec3::ZERO || right == Vec3::ZERO {
+            return;
+        }
+        let up = forward.cross(right);
+
+        self.rotation = Quat::from_mat3(&Mat3::from_cols(right, up, forward));
+    }
+
+    /// Combines this transform with a child transform,
+    /// the result is the child transform relative to the parent space
+    pub fn mul_transform(&self, child: &Transform) -> Transform {
+        Transform {
+            position: self.transform_point(child.position),
+            rotation: self.rotation * child.rotation,
+            scale: self.scale * child.scale,
+        }
+    }
+
+    /// Applies the scale, the rotation and the translation of this transform to a point
+    pub fn transform_point(&self, point: Vec3) -> Vec3 {
+        self.rotation * (self.scale * point) + self.position
+    }
+
     /// Gets the 3d trasnformation matrix from this transforms translation, rotation, and scale.
-    pub fn get_transformation_matrix(&self) -> glam::Mat4 {
-        let translation = glam::Mat4::from_translation(self.position);
+    pub fn get_transformation_matrix(&self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use glam::{Quat, Vec3};
+
+    use crate::Transform;
+
+    #[test]
+    fn euler_degrees_round_trip() {
+        let transform = Transform::default().with_euler_degrees(Vec3::new(10.0, 20.0, 30.0));
+        assert!(transform
+            .euler_degrees()
+            .abs_diff_eq(Vec3::new(10.0, 20.0, 30.0), 1e-3));
+    }
 
-        let rotation_x = glam::Mat4::from_rotation_x(self.rotation.x.to_radians());
-        let rotation_y = glam::Mat4::from_rotation_y(self.rotation.y.to_radians());
-        let rotation_z = glam::Mat4::from_rotation_z(self.rotation.z.to_radians());
+    #[test]
+    fn transform_point_matches_matrix() {
+        let transform = Transform::new(
+            Vec3::new(1.0, 2.0, 3.0),
+            Quat::from_rotation_z(FRAC_PI_2),
+            Vec3::new(2.0, 0.5, 1.0),
+        );
+        let point = Vec3::new(1.0, 1.0, 0.0);
+
+        let expected = transform
+            .get_transformation_matrix()
+            .transform_point3(point);
+        assert!(transform.transform_point(point).abs_diff_eq(expected, 1e-5));
+        assert!(expected.abs_diff_eq(Vec3::new(0.5, 4.0, 3.0), 1e-5));
+    }
+
+    #[test]
+    fn mul_transform_composes_matrices() {
+        let mut parent = Transform::from_position(Vec3::new(5.0, 0.0, 0.0));
+        parent.rotate_z(FRAC_PI_2);
+        parent.scale = Vec3::splat(2.0);
+        let child = Transform::from_position(Vec3::new(1.0, 0.0, 0.0));
+
+        let combined = parent.mul_transform(&child);
+        let expected = parent.get_transformation_matrix() * child.get_transformation_matrix();
+
+        assert!(combined
+            .get_transformation_matrix()
+            .abs_diff_eq(expected, 1e-5));
+        assert!(combined
+            .position
+            .abs_diff_eq(Vec3::new(5.0, 2.0, 0.0), 1e-5));
+    }
 
-        let scale = glam::Mat4::from_scale(glam::Vec3::from_array([self.scale; 3]));
+    #[test]
+    fn look_at_target() {
+        let mut transform = Transform::default();
+        transform.look_at(Vec3::new(10.0, 0.0, 0.0), Vec3::Y);
 
-        translation * (rotation_x * rotation_y * rotation_z) * scale
+        assert!((transform.rotation * Vec3::Z).abs_diff_eq(Vec3::X, 1e-5));
+        assert!((transform.rotation * Vec3::Y).abs_diff_eq(Vec3::Y, 1e-5));
     }
 }
@@ -0,0 +1,891 @@
+use std::{fmt::Debug, marker::PhantomData, time::Duration};
+
+use glam::{Quat, Vec3};
+use zengine_ecs::{
+    query::{Query, QueryIterMut},
+    system::{EventPublisher, Res},
+    Component, Entity,
+};
+use zengine_engine::{Engine, Module, ModuleId, Stage};
+use zengine_macro::Component;
+
+use crate::{EaseFunction, Time, TimeModule, Transform};
+
+/// Describes how a [Tween] changes a field of a component
+///
+/// The lens receives the eased progress of the tween, from `0.0` at the start
+/// to `1.0` at the end, and writes the interpolated value into the target.
+/// Any `FnMut(&mut C, f32)` closure can be used as a lens
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use zengine_core::{EaseFunction, Tween};
+/// use zengine_macro::Component;
+///
+/// #[derive(Component, Debug)]
+/// struct Health(f32);
+///
+/// let tween = Tween::new(
+///     EaseFunction::Linear,
+///     Duration::from_secs(1),
+///     |health: &mut Health, ratio: f32| health.0 = 100. * ratio,
+/// );
+/// ```
+pub trait Lens<C>: Send + Sync + 'static {
+    /// Updates the target with the value at the given progress
+    fn lerp(&mut self, target: &mut C, ratio: f32);
+}
+
+impl<C, F: FnMut(&mut C, f32) + Send + Sync + 'static> Lens<C> for F {
+    fn lerp(&mut self, target: &mut C, ratio: f32) {
+        self(target, ratio)
+    }
+}
+
+/// A [Lens] that moves the [Transform] position
+#[derive(Debug, Clone, Copy)]
+pub struct TransformPositionLens {
+    pub start: Vec3,
+    pub end: Vec3,
+}
+
+impl Lens<Transform> for TransformPositionLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        target.position = self.start.lerp(self.end, ratio);
+    }
+}
+
+/// A [Lens] that rotates the [Transform] following the shortest path
+/// between two rotations
+#[derive(Debug, Clone, Copy)]
+pub struct TransformRotationLens {
+    pub start: Quat,
+    pub end: Quat,
+}
+
+impl Lens<Transform> for TransformRotationLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        target.rotation = self.start.slerp(self.end, ratio);
+    }
+}
+
+/// A [Lens] that rotates the [Transform] around the Z axis
+/// between two angles in radians.
+///
+/// Unlike the [TransformRotationLens] it can describe rotations of more than half a turn
+#[derive(Debug, Clone, Copy)]
+pub struct TransformRotateZLens {
+    pub start: f32,
+    pub end: f32,
+}
+
+impl Lens<Transform> for TransformRotateZLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        let angle = self.start + (self.end - self.start) * ratio;
+        target.rotation = Quat::from_rotation_z(angle);
+    }
+}
+
+/// A [Lens] that changes the [Transform] scale
+#[derive(Debug, Clone, Copy)]
+pub struct TransformScaleLens {
+    pub start: Vec3,
+    pub end: Vec3,
+}
+
+impl Lens<Transform> for TransformScaleLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        target.scale = self.start.lerp(self.end, ratio);
+    }
+}
+
+/// Event published when a tween with a completion event finishes one of its cycles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TweenCompleted {
+    /// The entity that owns the [Animator]
+    pub entity: Entity,
+    /// The value passed to [Tween::with_completed_event]
+    pub user_data: u64,
+}
+
+/// The state returned by a [Tweenable] after a tick
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TweenState {
+    /// The animation is still running
+    Active,
+    /// The animation reached its end
+    Completed,
+}
+
+/// How many times a [Tween] is played
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatCount {
+    /// Plays the tween the given number of times
+    Finite(u32),
+    /// Plays the tween forever
+    Infinite,
+}
+
+impl Default for RepeatCount {
+    fn default() -> Self {
+        RepeatCount::Finite(1)
+    }
+}
+
+/// What a [Tween] does when it starts a new cycle
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatStrategy {
+    /// Restarts from the beginning
+    #[default]
+    Repeat,
+    /// Plays backward every other cycle, also known as yoyo
+    MirroredRepeat,
+}
+
+/// An animation that can be played by an [Animator]
+pub trait Tweenable<C>: Send + Sync + 'static {
+    /// Returns the total duration of the animation, `None` if it never ends
+    fn duration(&self) -> Option<Duration>;
+
+    /// Returns the time elapsed since the animation start
+    fn elapsed(&self) -> Duration;
+
+    /// Advances the animation by the given amount of time and updates the target
+    ///
+    /// The completion events of the tweens are pushed into `events`
+    fn tick(
+        &mut self,
+        delta: Duration,
+        target: &mut C,
+        entity: Entity,
+        events: &mut Vec<TweenCompleted>,
+    ) -> TweenState;
+
+    /// Restarts the animation from the beginning.
+    /// The target is updated at the next tick
+    fn rewind(&mut self);
+}
+
+/// Animates a single field of a component from a start value to an end value
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use glam::Vec3;
+/// use zengine_core::{EaseFunction, RepeatCount, RepeatStrategy, TransformScaleLens, Tween};
+///
+/// let pulse = Tween::new(
+///     EaseFunction::SineInOut,
+///     Duration::from_millis(500),
+///     TransformScaleLens {
+///         start: Vec3::ONE,
+///         end: Vec3::splat(1.2),
+///     },
+/// )
+/// .with_repeat_count(RepeatCount::Infinite)
+/// .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+/// ```
+pub struct Tween<C> {
+    ease: EaseFunction,
+    duration: Duration,
+    lens: Box<dyn Lens<C>>,
+    repeat_count: RepeatCount,
+    repeat_strategy: RepeatStrategy,
+    completed_event: Option<u64>,
+    elapsed: Duration,
+    times_completed: u32,
+}
+
+impl<C: 'static> Tween<C> {
+    /// Creates a tween that plays the lens one time in the given duration
+    pub fn new<L: Lens<C>>(ease: EaseFunction, duration: Duration, lens: L) -> Self {
+        Self {
+            ease,
+            duration,
+            lens: Box::new(lens),
+            repeat_count: RepeatCount::default(),
+            repeat_strategy: RepeatStrategy::default(),
+            completed_event: None,
+            elapsed: Duration::ZERO,
+            times_completed: 0,
+        }
+    }
+
+    /// Sets how many times the tween is played
+    ///
+    /// # Panics
+    /// Panics if the count is `RepeatCount::Finite(0)`
+    pub fn with_repeat_count(mut self, repeat_count: RepeatCount) -> Self {
+        assert!(
+            repeat_count != RepeatCount::Finite(0),
+            "The repeat count must be greater than zero"
+        );
+        self.repeat_count = repeat_count;
+        self
+    }
+
+    /// Sets what the tween does when it starts a new cycle
+    pub fn with_repeat_strategy(mut self, repeat_strategy: RepeatStrategy) -> Self {
+        self.repeat_strategy = repeat_strategy;
+        self
+    }
+
+    /// Publishes a [TweenCompleted] event with the given user data
+    /// each time the tween completes a cycle
+    pub fn with_completed_event(mut self, user_data: u64) -> Self {
+        self.completed_event = Some(user_data);
+        self
+    }
+
+    /// Returns a [Sequence] that plays this tween and then the given one
+    pub fn then<T: Tweenable<C>>(self, next: T) -> Sequence<C> {
+        Sequence::default().then(self).then(next)
+    }
+
+    /// Returns the duration of a single cycle
+    pub fn cycle_duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns how many cycles have been completed
+    pub fn times_completed(&self) -> u32 {
+        self.times_completed
+    }
+}
+
+impl<C: 'static> Tweenable<C> for Tween<C> {
+    fn duration(&self) -> Option<Duration> {
+        match self.repeat_count {
+            _ if self.duration.is_zero() => Some(Duration::ZERO),
+            RepeatCount::Finite(times) => Some(self.duration * times),
+            RepeatCount::Infinite => None,
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        target: &mut C,
+        entity: Entity,
+        events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        let total = self.duration();
+        if total.is_some_and(|total| self.times_completed > 0 && self.elapsed >= total) {
+            return TweenState::Completed;
+        }
+
+        self.elapsed = self.elapsed.saturating_add(delta);
+        let finished = total.is_some_and(|total| self.elapsed >= total);
+
+        let (cycle, ratio, times_completed) = if finished {
+            self.elapsed = total.unwrap_or_default();
+            let times = match self.repeat_count {
+                RepeatCount::Finite(times) if !self.duration.is_zero() => times,
+                _ => 1,
+            };
+            (times - 1, 1., times)
+        } else {
+            let cycles = self.elapsed.as_nanos() / self.duration.as_nanos();
+            let cycle = cycles.min(u32::MAX as u128) as u32;
+            let local = self.elapsed - self.duration * cycle;
+            (
+                cycle,
+                local.as_secs_f32() / self.duration.as_secs_f32(),
+                cycle,
+            )
+        };
+
+        let ratio = if self.repeat_strategy == RepeatStrategy::MirroredRepeat && cycle % 2 == 1 {
+            1. - ratio
+        } else {
+            ratio
+        };
+        self.lens.lerp(target, self.ease.sample(ratio));
+
+        if let Some(user_data) = self.completed_event {
+            for _ in self.times_completed..times_completed {
+                events.push(TweenCompleted { entity, user_data });
+            }
+        }
+        self.times_completed = times_completed;
+
+        if finished {
+            TweenState::Completed
+        } else {
+            TweenState::Active
+        }
+    }
+
+    fn rewind(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.times_completed = 0;
+    }
+}
+
+/// Waits for the given amount of time without changing the target.
+/// Useful to add a pause inside a [Sequence]
+pub struct Delay<C> {
+    duration: Duration,
+    elapsed: Duration,
+    phantom: PhantomData<fn() -> C>,
+}
+
+impl<C> Delay<C> {
+    /// Creates a delay of the given duration
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            elapsed: Duration::ZERO,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<C: 'static> Tweenable<C> for Delay<C> {
+    fn duration(&self) -> Option<Duration> {
+        Some(self.duration)
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        _target: &mut C,
+        _entity: Entity,
+        _events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        self.elapsed = self.elapsed.saturating_add(delta).min(self.duration);
+        if self.elapsed >= self.duration {
+            TweenState::Completed
+        } else {
+            TweenState::Active
+        }
+    }
+
+    fn rewind(&mut self) {
+        self.elapsed = Duration::ZERO;
+    }
+}
+
+/// Plays a list of [Tweenable]s one after the other
+///
+/// The time left over by an animation is passed to the next one
+pub struct Sequence<C> {
+    tweens: Vec<Box<dyn Tweenable<C>>>,
+    index: usize,
+}
+
+impl<C> Default for Sequence<C> {
+    fn default() -> Self {
+        Self {
+            tweens: Vec::default(),
+            index: 0,
+        }
+    }
+}
+
+impl<C: 'static> Sequence<C> {
+    /// Appends an animation at the end of the sequence
+    pub fn then<T: Tweenable<C>>(mut self, next: T) -> Self {
+        self.tweens.push(Box::new(next));
+        self
+    }
+
+    /// Returns the index of the animation that is currently playing
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<C: 'static> Tweenable<C> for Sequence<C> {
+    fn duration(&self) -> Option<Duration> {
+        self.tweens.iter().map(|tween| tween.duration()).sum()
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.tweens.iter().map(|tween| tween.elapsed()).sum()
+    }
+
+    fn tick(
+        &mut self,
+        mut delta: Duration,
+        target: &mut C,
+        entity: Entity,
+        events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        while let Some(tween) = self.tweens.get_mut(self.index) {
+            let remaining = tween
+                .duration()
+                .map(|duration| duration.saturating_sub(tween.elapsed()));
+
+            if tween.tick(delta, target, entity, events) == TweenState::Active {
+                return TweenState::Active;
+            }
+
+            delta = delta.saturating_sub(remaining.unwrap_or(delta));
+            self.index += 1;
+        }
+
+        TweenState::Completed
+    }
+
+    fn rewind(&mut self) {
+        self.index = 0;
+        for tween in self.tweens.iter_mut() {
+            tween.rewind();
+        }
+    }
+}
+
+/// Plays a list of [Tweenable]s at the same time
+///
+/// The tracks should animate different fields of the target,
+/// otherwise the last track overwrites the others
+pub struct Tracks<C> {
+    tracks: Vec<Box<dyn Tweenable<C>>>,
+}
+
+impl<C> Default for Tracks<C> {
+    fn default() -> Self {
+        Self {
+            tracks: Vec::default(),
+        }
+    }
+}
+
+impl<C: 'static> Tracks<C> {
+    /// Adds an animation that plays together with the others
+    pub fn with_track<T: Tweenable<C>>(mut self, track: T) -> Self {
+        self.tracks.push(Box::new(track));
+        self
+    }
+}
+
+impl<C: 'static> Tweenable<C> for Tracks<C> {
+    fn duration(&self) -> Option<Duration> {
+        self.tracks
+            .iter()
+            .map(|track| track.duration())
+            .try_fold(Duration::ZERO, |max, duration| duration.map(|d| d.max(max)))
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.tracks
+            .iter()
+            .map(|track| track.elapsed())
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        target: &mut C,
+        entity: Entity,
+        events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        let mut state = TweenState::Completed;
+        for track in self.tracks.iter_mut() {
+            if track.tick(delta, target, entity, events) == TweenState::Active {
+                state = TweenState::Active;
+            }
+        }
+
+        state
+    }
+
+    fn rewind(&mut self) {
+        for track in self.tracks.iter_mut() {
+            track.rewind();
+        }
+    }
+}
+
+/// The playback state of an [Animator]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AnimatorState {
+    /// The animation advances each frame
+    #[default]
+    Playing,
+    /// The animation is stopped
+    Paused,
+}
+
+/// A [Component](zengine_ecs::Component) that plays a [Tweenable]
+/// on a component of the same entity
+///
+/// The animators are advanced by the [TweenModule] using the virtual [Time]
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use glam::Vec3;
+/// use zengine_core::{Animator, EaseFunction, Transform, TransformPositionLens, Tween};
+/// use zengine_ecs::World;
+///
+/// let slide_in = Tween::new(
+///     EaseFunction::BackOut,
+///     Duration::from_millis(300),
+///     TransformPositionLens {
+///         start: Vec3::new(-100., 0., 0.),
+///         end: Vec3::ZERO,
+///     },
+/// )
+/// .with_completed_event(1);
+///
+/// let mut world = World::default();
+/// world.spawn((Transform::default(), Animator::new(slide_in)));
+/// ```
+#[derive(Component)]
+pub struct Animator<C: Component> {
+    tweenable: Box<dyn Tweenable<C>>,
+    state: AnimatorState,
+    speed: f32,
+    completed: bool,
+}
+
+impl<C: Component> Debug for Animator<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Animator")
+            .field("state", &self.state)
+            .field("speed", &self.speed)
+            .field("completed", &self.completed)
+            .finish()
+    }
+}
+
+impl<C: Component> Animator<C> {
+    /// Creates an animator that starts playing the given animation
+    pub fn new<T: Tweenable<C>>(tweenable: T) -> Self {
+        Self {
+            tweenable: Box::new(tweenable),
+            state: AnimatorState::default(),
+            speed: 1.,
+            completed: false,
+        }
+    }
+
+    /// Sets the initial [AnimatorState]
+    pub fn with_state(mut self, state: AnimatorState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Sets the playback speed
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.set_speed(speed);
+        self
+    }
+
+    /// Returns the [AnimatorState]
+    pub fn state(&self) -> AnimatorState {
+        self.state
+    }
+
+    /// Sets the [AnimatorState]
+    pub fn set_state(&mut self, state: AnimatorState) {
+        self.state = state;
+    }
+
+    /// Stops the animation
+    pub fn pause(&mut self) {
+        self.state = AnimatorState::Paused;
+    }
+
+    /// Resumes the animation
+    pub fn resume(&mut self) {
+        self.state = AnimatorState::Playing;
+    }
+
+    /// Returns the playback speed
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Sets the playback speed, `2.0` plays the animation twice as fast
+    ///
+    /// # Panics
+    /// Panics if the speed is negative or not finite
+    pub fn set_speed(&mut self, speed: f32) {
+        assert!(
+            speed.is_finite() && speed >= 0.,
+            "The animator speed must be a finite positive number"
+        );
+        self.speed = speed;
+    }
+
+    /// Returns the animation played by the animator
+    pub fn tweenable(&self) -> &dyn Tweenable<C> {
+        self.tweenable.as_ref()
+    }
+
+    /// Replaces the animation played by the animator
+    pub fn set_tweenable<T: Tweenable<C>>(&mut self, tweenable: T) {
+        self.tweenable = Box::new(tweenable);
+        self.completed = false;
+    }
+
+    /// Returns `true` if the animation reached its end
+    pub fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Restarts the animation from the beginning
+    pub fn rewind(&mut self) {
+        self.tweenable.rewind();
+        self.completed = false;
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        target: &mut C,
+        entity: Entity,
+        events: &mut Vec<TweenCompleted>,
+    ) {
+        if self.state == AnimatorState::Paused || self.completed {
+            return;
+        }
+
+        let state = self
+            .tweenable
+            .tick(delta.mul_f32(self.speed), target, entity, events);
+        self.completed = state == TweenState::Completed;
+    }
+}
+
+/// Advances all the [Animator]s of the given component type
+/// and publishes the [TweenCompleted] events
+pub fn animate_component<C: Component>(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Animator<C>, &mut C)>,
+    mut completed: EventPublisher<TweenCompleted>,
+) {
+    let mut events = Vec::default();
+    for (entity, animator, target) in query.iter_mut() {
+        animator.tick(time.delta(), target, *entity, &mut events);
+    }
+
+    for event in events {
+        completed.publish(event);
+    }
+}
+
+/// Adds to the engine the systems that play the [Animator]s
+///
+/// The [Transform] animators are always played, other components must be registered
+/// with [TweenModule::with_component]. The animators are advanced in the
+/// [PostUpdate Stage](Stage::PostUpdate) after the game logic
+///
+/// # Example
+/// ```
+/// use zengine_core::{TimeModule, TweenModule};
+/// use zengine_engine::Engine;
+/// use zengine_macro::Component;
+///
+/// #[derive(Component, Debug)]
+/// struct Health(f32);
+///
+/// Engine::default()
+///     .add_module(TimeModule(None))
+///     .add_module(TweenModule::default().with_component::<Health>());
+/// ```
+#[derive(Default)]
+pub struct TweenModule {
+    components: Vec<fn(&mut Engine)>,
+}
+
+impl TweenModule {
+    /// Plays the [Animator]s of the given component type
+    pub fn with_component<C: Component>(mut self) -> Self {
+        self.components.push(add_animate_system::<C>);
+        self
+    }
+}
+
+fn add_animate_system<C: Component>(engine: &mut Engine) {
+    engine.add_system_into_stage(animate_component::<C>, Stage::PostUpdate);
+}
+
+impl Module for TweenModule {
+    fn init(self, engine: &mut Engine) {
+        add_animate_system::<Transform>(engine);
+        for add_system in self.components {
+            add_system(engine);
+        }
+    }
+
+    fn dependencies(&self) -> Vec<ModuleId> {
+        vec![ModuleId::of::<TimeModule>()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use glam::Vec3;
+    use zengine_ecs::{
+        query::QueryIter,
+        system::{EventStream, ResMut},
+        Entity, World,
+    };
+    use zengine_engine::{Engine, HeadlessRunner};
+    use zengine_macro::{Component, Resource};
+
+    use crate::{
+        Animator, Delay, EaseFunction, RepeatCount, RepeatStrategy, TimeModule, Tracks, Transform,
+        TransformPositionLens, TransformScaleLens, Tween, TweenCompleted, TweenModule, TweenState,
+        Tweenable,
+    };
+
+    fn entity() -> Entity {
+        World::default().spawn(())
+    }
+
+    fn position_tween(start: f32, end: f32) -> Tween<Transform> {
+        Tween::new(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::splat(start),
+                end: Vec3::splat(end),
+            },
+        )
+    }
+
+    #[test]
+    fn yoyo_tween_with_events() {
+        let mut tween = position_tween(0., 10.)
+            .with_repeat_count(RepeatCount::Finite(3))
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+            .with_completed_event(7);
+        let mut transform = Transform::default();
+        let mut events = Vec::default();
+        let entity = entity();
+
+        let state = tween.tick(
+            Duration::from_millis(1250),
+            &mut transform,
+            entity,
+            &mut events,
+        );
+        assert_eq!(state, TweenState::Active);
+        assert!(transform.position.abs_diff_eq(Vec3::splat(7.5), 1e-4));
+        assert_eq!(
+            events,
+            vec![TweenCompleted {
+                entity,
+                user_data: 7
+            }]
+        );
+
+        let state = tween.tick(Duration::from_secs(5), &mut transform, entity, &mut events);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(transform.position, Vec3::splat(10.));
+        assert_eq!(events.len(), 3);
+        assert_eq!(tween.elapsed(), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn sequence_and_tracks() {
+        let mut sequence = position_tween(0., 10.)
+            .then(Delay::new(Duration::from_secs(1)))
+            .then(
+                Tracks::default()
+                    .with_track(position_tween(10., 20.))
+                    .with_track(Tween::new(
+                        EaseFunction::Linear,
+                        Duration::from_secs(2),
+                        TransformScaleLens {
+                            start: Vec3::ONE,
+                            end: Vec3::splat(3.),
+                        },
+                    )),
+            );
+        assert_eq!(sequence.duration(), Some(Duration::from_secs(4)));
+
+        let mut transform = Transform::default();
+        let mut events = Vec::default();
+        let entity = entity();
+
+        sequence.tick(
+            Duration::from_millis(2500),
+            &mut transform,
+            entity,
+            &mut events,
+        );
+        assert_eq!(sequence.index(), 2);
+        assert!(transform.position.abs_diff_eq(Vec3::splat(15.), 1e-4));
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(1.5), 1e-4));
+
+        let state = sequence.tick(Duration::from_secs(1), &mut transform, entity, &mut events);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(transform.position, Vec3::splat(20.));
+
+        let state = sequence.tick(Duration::from_secs(1), &mut transform, entity, &mut events);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(transform.scale, Vec3::splat(3.));
+
+        sequence.rewind();
+        sequence.tick(Duration::ZERO, &mut transform, entity, &mut events);
+        assert_eq!(transform.position, Vec3::ZERO);
+    }
+
+    #[derive(Component, Debug)]
+    struct Health(f32);
+
+    #[derive(Resource, Debug, Default)]
+    struct Completed(u32);
+
+    fn count_completed(events: EventStream<TweenCompleted>, mut completed: ResMut<Completed>) {
+        completed.0 += events.read().count() as u32;
+    }
+
+    fn health(world: &mut World) -> (f32, bool) {
+        let mut query = world.query::<(&Health, &Animator<Health>)>();
+        let query = query.run(world);
+        let (health, animator) = query.iter().next().unwrap();
+        (health.0, animator.is_completed())
+    }
+
+    #[test]
+    fn animate_custom_component() {
+        let mut engine = Engine::default();
+        engine
+            .add_module(TimeModule(None))
+            .add_module(TweenModule::default().with_component::<Health>())
+            .add_system(count_completed);
+
+        engine.world.spawn((
+            Health(0.),
+            Animator::new(
+                Tween::new(
+                    EaseFunction::Linear,
+                    Duration::from_secs(1),
+                    |health: &mut Health, ratio: f32| health.0 = 100. * ratio,
+                )
+                .with_completed_event(1),
+            ),
+        ));
+
+        HeadlessRunner::frames(3)
+            .with_time_step(Duration::from_millis(250))
+            .run(&mut engine);
+        assert_eq!(health(&mut engine.world), (75., false));
+
+        for _ in 0..3 {
+            engine.update();
+        }
+        assert_eq!(health(&mut engine.world), (100., true));
+        assert_eq!(engine.world.get_resource::<Completed>().unwrap().0, 1);
+    }
+}
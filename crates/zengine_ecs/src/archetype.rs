@@ -1,8 +1,9 @@
 use crate::{component::ComponentColumn, entity::Entity};
+use rustc_hash::FxHashMap;
 use std::{
     any::TypeId,
     hash::{Hash, Hasher},
-    sync::RwLock,
+    sync::{Arc, RwLock},
 };
 
 pub type ArchetypeId = u64;
@@ -14,11 +15,53 @@ pub(crate) fn calculate_archetype_id(types: &[TypeId]) -> ArchetypeId {
     s.finish()
 }
 
+/// Where a component of a bundle is written in the destination archetype
+#[derive(Debug)]
+pub(crate) enum BundleColumn {
+    Add(usize),
+    Replace(usize),
+}
+
+/// Cached transition that adds a bundle to the entities of an archetype
+#[derive(Debug)]
+pub(crate) struct AddEdge {
+    pub(crate) destination: usize,
+    /// destination column of each bundle component, in bundle order
+    pub(crate) columns: Vec<BundleColumn>,
+    /// (source column, destination column) pairs to migrate
+    pub(crate) migration: Vec<(usize, usize)>,
+}
+
+/// Cached transition that removes a bundle from the entities of an archetype
+#[derive(Debug)]
+pub(crate) struct RemoveEdge {
+    pub(crate) destination: usize,
+    /// (source column, destination column) pairs to migrate
+    pub(crate) migration: Vec<(usize, usize)>,
+}
+
+/// Edges of the archetype graph keyed by the bundle type
+#[derive(Debug, Default)]
+pub(crate) struct ArchetypeEdges {
+    pub(crate) add: FxHashMap<TypeId, Arc<AddEdge>>,
+    pub(crate) remove: FxHashMap<TypeId, Arc<RemoveEdge>>,
+}
+
+/// Runtime information about an archetype of the [World](crate::World)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchetypeInfo {
+    /// Type names of the components stored in the archetype
+    pub components: Vec<&'static str>,
+    /// Number of entities stored in the archetype
+    pub entities: usize,
+}
+
 #[derive(Debug)]
 pub struct Archetype {
     pub(crate) archetype_specs: ArchetypeSpecs,
     pub(crate) entities: Vec<Entity>,
     pub(crate) components: Vec<Box<dyn ComponentColumn>>,
+    pub(crate) edges: ArchetypeEdges,
 }
 
 impl Archetype {
@@ -27,6 +70,7 @@ impl Archetype {
             archetype_specs: vec![],
             entities: Vec::default(),
             components: Vec::default(),
+            edges: ArchetypeEdges::default(),
         }
     }
 
@@ -38,6 +82,7 @@ impl Archetype {
             archetype_specs,
             entities: Vec::default(),
             components: Vec::with_capacity(from_components.len()),
+            edges: ArchetypeEdges::default(),
         };
 
         from_components.sort_by(|(a_type, _), (b_type, _)| a_type.cmp(b_type));
@@ -63,6 +108,17 @@ impl Archetype {
             .migrate(entity_row, &mut *other_archetype.components[other_index]);
     }
 
+    pub fn info(&self) -> ArchetypeInfo {
+        ArchetypeInfo {
+            components: self
+                .components
+                .iter()
+                .map(|column| column.component_name())
+                .collect(),
+            entities: self.entities.len(),
+        }
+    }
+
     pub(crate) fn get<T: 'static>(&self, index: usize) -> &RwLock<Vec<T>> {
         self.components[index]
             .to_any()
@@ -38,7 +38,7 @@ pub enum InsertType {
 }
 
 #[doc(hidden)]
-pub trait ComponentBundle {
+pub trait ComponentBundle: 'static {
     fn get_types() -> Vec<TypeId>;
 
     fn get_component_columns() -> Vec<(TypeId, Box<dyn ComponentColumn>)>;
@@ -105,6 +105,8 @@ pub trait ComponentColumn: Debug {
     fn swap_remove(&mut self, row_index: usize) -> Box<dyn Component>;
     fn new_same_type(&self) -> (TypeId, Box<dyn ComponentColumn>);
     fn migrate(&mut self, row_index: usize, other_component_vec: &mut dyn ComponentColumn);
+    fn reserve(&mut self, additional: usize);
+    fn component_name(&self) -> &'static str;
 }
 
 impl<T: Component> ComponentColumn for RwLock<Vec<T>> {
@@ -127,6 +129,14 @@ impl<T: Component> ComponentColumn for RwLock<Vec<T>> {
         let data: T = self.get_mut().unwrap().swap_remove(row_index);
         component_vec_to_mut(other_component_vec).push(data);
     }
+
+    fn reserve(&mut self, additional: usize) {
+        self.get_mut().unwrap().reserve(additional);
+    }
+
+    fn component_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
 }
 
 pub(crate) fn component_vec_to_mut<T: Component>(c: &mut dyn ComponentColumn) -> &mut Vec<T> {
@@ -44,6 +44,11 @@ impl EntityGenerator {
         let current = self.current.fetch_add(1, Ordering::Relaxed);
         Entity(current)
     }
+
+    pub fn generate_batch(&self, count: usize) -> Vec<Entity> {
+        let first = self.current.fetch_add(count, Ordering::Relaxed);
+        (first..first + count).map(Entity).collect()
+    }
 }
 
 #[cfg(test)]
@@ -67,4 +72,18 @@ mod tests {
         );
         assert_eq!(*generator.current.get_mut(), 4);
     }
+
+    #[test]
+    fn generate_a_batch_of_entities() {
+        let generator = EntityGenerator::default();
+        generator.generate();
+
+        let entities = generator.generate_batch(3);
+
+        assert_eq!(
+            entities.into_iter().map(|e| e.0).collect::<Vec<usize>>(),
+            vec!(1, 2, 3)
+        );
+        assert_eq!(generator.generate().0, 4);
+    }
 }
@@ -11,6 +11,7 @@ const STREAM_SIZE_BLOCK: usize = 10;
 pub trait EventCell: Debug {
     fn to_any(&self) -> &dyn Any;
     fn to_any_mut(&mut self) -> &mut dyn Any;
+    fn info(&self) -> EventHandlerInfo;
 }
 
 impl<T: Any + Debug> EventCell for RwLock<EventHandler<T>> {
@@ -20,6 +21,38 @@ impl<T: Any + Debug> EventCell for RwLock<EventHandler<T>> {
     fn to_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+    fn info(&self) -> EventHandlerInfo {
+        let (buffer_len, buffer_capacity, subscribers) = self
+            .try_read()
+            .map(|handler| {
+                (
+                    handler.buffer.len(),
+                    handler.buffer.capacity(),
+                    handler.subscriptions.len(),
+                )
+            })
+            .unwrap_or_default();
+
+        EventHandlerInfo {
+            event: std::any::type_name::<T>(),
+            buffer_len,
+            buffer_capacity,
+            subscribers,
+        }
+    }
+}
+
+/// Runtime information about an [EventHandler]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventHandlerInfo {
+    /// Type name of the event
+    pub event: &'static str,
+    /// Number of events stored in the buffer
+    pub buffer_len: usize,
+    /// Capacity of the event buffer
+    pub buffer_capacity: usize,
+    /// Number of subscribers to the event queue
+    pub subscribers: usize,
 }
 
 /// Handle event of a specific type
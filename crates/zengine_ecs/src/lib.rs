@@ -10,6 +10,7 @@ mod resource;
 pub mod system;
 mod world;
 
+pub use archetype::ArchetypeInfo;
 pub use component::*;
 pub use entity::*;
 pub use resource::*;
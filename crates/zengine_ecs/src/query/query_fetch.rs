@@ -1,4 +1,10 @@
-use crate::{archetype::Archetype, component::Component, entity::Entity, world::World};
+use crate::{
+    archetype::Archetype,
+    component::Component,
+    entity::Entity,
+    system::{AccessTarget, SystemAccess},
+    world::World,
+};
 use std::{
     any::TypeId,
     sync::{RwLockReadGuard, RwLockWriteGuard},
@@ -8,13 +14,17 @@ use super::{query_iterators::*, QueryCache};
 use zengine_macro::all_tuples;
 
 #[doc(hidden)]
-pub trait QueryParameters: for<'a> QueryParameterFetch<'a> {}
+pub trait QueryParameters: for<'a> QueryParameterFetch<'a> {
+    fn access(access: &mut SystemAccess);
+}
 
 #[doc(hidden)]
 pub trait QueryParameter {
     type Item: for<'a> QueryParameterFetchFromArchetype<'a>;
 
     fn matches_archetype(archetype: &Archetype) -> bool;
+
+    fn access(_access: &mut SystemAccess) {}
 }
 
 #[doc(hidden)]
@@ -110,6 +120,10 @@ impl<T: Component + 'static> QueryParameter for &T {
         let type_id = TypeId::of::<T>();
         archetype.archetype_specs.iter().any(|c| *c == type_id)
     }
+
+    fn access(access: &mut SystemAccess) {
+        access.add_read::<T>(AccessTarget::Component);
+    }
 }
 
 impl<'a, T: Component + 'static> QueryParameterFetch<'a> for ReadQueryParameterFetch<T> {
@@ -195,6 +209,10 @@ impl<T: Component + 'static> QueryParameter for &mut T {
         let type_id = TypeId::of::<T>();
         archetype.archetype_specs.iter().any(|c| *c == type_id)
     }
+
+    fn access(access: &mut SystemAccess) {
+        access.add_write::<T>(AccessTarget::Component);
+    }
 }
 
 impl<'a, T: Component + 'static> QueryParameterFetch<'a> for WriteQueryParameterFetch<T> {
@@ -274,6 +292,10 @@ impl<T: Component + 'static> QueryParameter for Option<&T> {
     fn matches_archetype(_archetype: &Archetype) -> bool {
         true
     }
+
+    fn access(access: &mut SystemAccess) {
+        access.add_read::<T>(AccessTarget::Component);
+    }
 }
 
 impl<'a, T: Component + 'static> QueryParameterFetch<'a> for Option<ReadQueryParameterFetch<T>> {
@@ -367,6 +389,10 @@ impl<T: Component + 'static> QueryParameter for Option<&mut T> {
     fn matches_archetype(_archetype: &Archetype) -> bool {
         true
     }
+
+    fn access(access: &mut SystemAccess) {
+        access.add_write::<T>(AccessTarget::Component);
+    }
 }
 
 impl<'a, T: Component + 'static> QueryParameterFetch<'a> for Option<WriteQueryParameterFetch<T>> {
@@ -457,7 +483,11 @@ impl<'a, T: Component + 'static> QueryParameterFetchFromArchetype<'a>
 macro_rules! impl_query_parameters {
     () => {};
     ($ty: ident) => {
-        impl<$ty: QueryParameter> QueryParameters for ($ty,) {}
+        impl<$ty: QueryParameter> QueryParameters for ($ty,) {
+            fn access(access: &mut SystemAccess) {
+                $ty::access(access);
+            }
+        }
 
         impl<'a, $ty: QueryParameter> QueryParameterFetch<'a> for ($ty,) {
             type FetchItem = Vec<<$ty::Item as QueryParameterFetchFromArchetype<'a>>::ArchetypeFetchItem>;
@@ -497,7 +527,11 @@ macro_rules! impl_query_parameters {
         }
     };
     ($($ty: ident),+) => {
-        impl<$($ty: QueryParameter),*> QueryParameters for ($($ty,)*) {}
+        impl<$($ty: QueryParameter),*> QueryParameters for ($($ty,)*) {
+            fn access(access: &mut SystemAccess) {
+                $($ty::access(access);)*
+            }
+        }
 
         impl<'a, $($ty: QueryParameter),*> QueryParameterFetch<'a> for ($($ty,)*) {
             type FetchItem = Vec<( $(<$ty::Item as QueryParameterFetchFromArchetype<'a>>::ArchetypeFetchItem),*)>;
@@ -0,0 +1,131 @@
+use std::{any::TypeId, fmt};
+
+/// The kind of data accessed by a system parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessTarget {
+    Component,
+    Resource,
+    UnsendableResource,
+    Event,
+}
+
+impl fmt::Display for AccessTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccessTarget::Component => write!(f, "component"),
+            AccessTarget::Resource => write!(f, "resource"),
+            AccessTarget::UnsendableResource => write!(f, "unsendable resource"),
+            AccessTarget::Event => write!(f, "event"),
+        }
+    }
+}
+
+/// A single data access registered by a system parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Access {
+    pub target: AccessTarget,
+    pub type_id: TypeId,
+    pub type_name: &'static str,
+    pub write: bool,
+}
+
+impl Access {
+    fn conflicts_with(&self, other: &Access) -> bool {
+        self.target == other.target && self.type_id == other.type_id && (self.write || other.write)
+    }
+}
+
+/// Two accesses of the same system that can't be held at the same time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessConflict {
+    pub first: Access,
+    pub second: Access,
+}
+
+impl fmt::Display for AccessConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = |access: &Access| if access.write { "mutable" } else { "shared" };
+        write!(
+            f,
+            "{} {} is borrowed as {} and as {}",
+            self.first.target,
+            self.first.type_name,
+            kind(&self.first),
+            kind(&self.second)
+        )
+    }
+}
+
+/// Collects the data accessed by the parameters of a system
+///
+/// Each system parameter registers its accesses during the init phase.
+/// An access that conflicts with a previous one is stored as an [AccessConflict]
+#[derive(Debug, Default)]
+pub struct SystemAccess {
+    accesses: Vec<Access>,
+    conflicts: Vec<AccessConflict>,
+}
+
+impl SystemAccess {
+    /// Registers a shared access to a type
+    pub fn add_read<T: 'static>(&mut self, target: AccessTarget) {
+        self.add(Access {
+            target,
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+            write: false,
+        });
+    }
+
+    /// Registers a mutable access to a type
+    pub fn add_write<T: 'static>(&mut self, target: AccessTarget) {
+        self.add(Access {
+            target,
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+            write: true,
+        });
+    }
+
+    fn add(&mut self, access: Access) {
+        self.check(&access, self.accesses.len());
+        self.accesses.push(access);
+    }
+
+    fn check(&mut self, access: &Access, len: usize) {
+        if let Some(previous) = self.accesses[..len]
+            .iter()
+            .find(|previous| previous.conflicts_with(access))
+        {
+            self.conflicts.push(AccessConflict {
+                first: *previous,
+                second: *access,
+            });
+        }
+    }
+
+    /// Merges a list of accesses that are never held at the same time
+    ///
+    /// The accesses of each set are checked only against the accesses
+    /// registered before the merge and not against each other
+    pub fn merge_disjoint(&mut self, sets: Vec<SystemAccess>) {
+        let len = self.accesses.len();
+        for set in sets {
+            for access in set.accesses {
+                self.check(&access, len);
+                self.accesses.push(access);
+            }
+            self.conflicts.extend(set.conflicts);
+        }
+    }
+
+    /// Returns all the registered accesses
+    pub fn accesses(&self) -> &[Access] {
+        &self.accesses
+    }
+
+    /// Returns the conflicts found between the registered accesses
+    pub fn conflicts(&self) -> &[AccessConflict] {
+        &self.conflicts
+    }
+}
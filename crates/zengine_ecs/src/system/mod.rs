@@ -59,14 +59,24 @@
 //! - [EventPublisher] to publish an event
 //! - [Commands] to send command to the [World]
 //! - [Local] to get access to data owned by the system
+//! - [ParamSet] to get access to a set of conflicting parameters, one at a time
+//!
+//! # Access validation
+//! During the init phase each parameter registers the data it accesses into a [SystemAccess].
+//! A system that borrows the same data mutably more than once (e.g. `Query<(&mut A,)>`
+//! together with `Query<(&A,)>` or `Res<R>` together with `ResMut<R>`) is rejected
+//! by the engine at startup. Use a [ParamSet] when the conflicting parameters
+//! are never used at the same time.
 
-use std::marker::PhantomData;
+use std::{any::type_name, marker::PhantomData};
 
 use zengine_macro::all_tuples;
 
 use crate::world::World;
 
+mod access;
 mod system_parameter;
+pub use access::*;
 pub use system_parameter::*;
 
 /// A trait implemented for all functions that can be used as a [System]
@@ -91,6 +101,7 @@ where
             _marker: PhantomData,
             function: self,
             param_state: Param::Fetch::default(),
+            access: SystemAccess::default(),
         }
     }
 }
@@ -100,10 +111,18 @@ pub struct SystemWrapper<F: SystemFunction<P>, P: SystemParam> {
     _marker: std::marker::PhantomData<P>,
     function: F,
     param_state: P::Fetch,
+    access: SystemAccess,
 }
 
 /// System trait
 pub trait System {
+    /// Returns the name of the system
+    fn name(&self) -> &'static str;
+
+    /// Returns the data accessed by the system parameters.
+    /// It's populated during the init phase
+    fn access(&self) -> &SystemAccess;
+
     fn init(&mut self, world: &mut World);
 
     fn run(&mut self, world: &World);
@@ -112,8 +131,17 @@ pub trait System {
 }
 
 impl<F: SystemFunction<P>, P: SystemParam> System for SystemWrapper<F, P> {
+    fn name(&self) -> &'static str {
+        type_name::<F>()
+    }
+
+    fn access(&self) -> &SystemAccess {
+        &self.access
+    }
+
     fn init(&mut self, world: &mut World) {
-        self.param_state.init(world);
+        self.access = SystemAccess::default();
+        self.param_state.init(world, &mut self.access);
     }
 
     fn run(&mut self, world: &World) {
@@ -160,10 +188,10 @@ macro_rules! impl_system_function {
         impl<'a, $($param: SystemParamFetch<'a>),*> SystemParamFetch<'a> for ($($param,)*) {
             type Item = ($($param::Item,)*);
 
-            fn init(&mut self, world: &mut World) {
+            fn init(&mut self, world: &mut World, access: &mut SystemAccess) {
                 let ($($param,)*) = self;
 
-                ($($param::init($param, world),)*);
+                ($($param::init($param, world, access),)*);
             }
 
             fn fetch(&'a mut self, world: &'a World) -> Self::Item {
@@ -211,9 +239,10 @@ mod tests {
     use crate::{query::Query, world::World, Component, Resource};
 
     use super::{
-        system_parameter::{Local, Res},
-        IntoSystem, System, SystemParam,
+        system_parameter::{Local, ParamSet, Res, ResMut},
+        AccessTarget, IntoSystem, System, SystemParam,
     };
+    use crate::query::{QueryIter, QueryIterMut};
 
     #[derive(Default)]
     struct Executor {
@@ -285,4 +314,69 @@ mod tests {
             .add_system(test4)
             .run();
     }
+
+    fn conflicting_query(_q1: Query<(&mut Component1,)>, _q2: Query<(&Component1,)>) {}
+    fn conflicting_res(_res: Res<Resource1>, _res_mut: ResMut<Resource1>) {}
+    #[allow(clippy::type_complexity)]
+    fn param_set(mut set: ParamSet<(Query<(&mut Component1,)>, Query<(&Component1,)>)>) {
+        for c in set.p0().iter_mut() {
+            c._data += 1;
+        }
+
+        assert!(set.p1().iter().all(|c| c._data == 2));
+    }
+    fn conflicting_param_set(
+        _set: ParamSet<(Query<(&mut Component1,)>, Res<Resource1>)>,
+        _res_mut: ResMut<Resource1>,
+    ) {
+    }
+
+    fn init_system<Params: SystemParam + 'static>(
+        world: &mut World,
+        system: impl IntoSystem<Params> + 'static,
+    ) -> Box<dyn System> {
+        let mut system: Box<dyn System> = Box::new(system.into_system());
+        system.init(world);
+
+        system
+    }
+
+    #[test]
+    fn detect_query_conflict() {
+        let mut world = World::default();
+        let system = init_system(&mut world, conflicting_query);
+
+        let conflict = system.access().conflicts().first().unwrap();
+        assert_eq!(conflict.first.target, AccessTarget::Component);
+        assert!(conflict.first.type_name.ends_with("Component1"));
+        assert!(system.name().ends_with("conflicting_query"));
+    }
+
+    #[test]
+    fn detect_resource_conflict() {
+        let mut world = World::default();
+        let system = init_system(&mut world, conflicting_res);
+
+        let conflict = system.access().conflicts().first().unwrap();
+        assert_eq!(conflict.first.target, AccessTarget::Resource);
+    }
+
+    #[test]
+    fn param_set_allows_disjoint_use() {
+        let mut world = World::default();
+        world.spawn(Component1 { _data: 1 });
+
+        let mut system = init_system(&mut world, param_set);
+        assert!(system.access().conflicts().is_empty());
+
+        system.run(&world);
+    }
+
+    #[test]
+    fn param_set_conflicts_with_other_parameters() {
+        let mut world = World::default();
+        let system = init_system(&mut world, conflicting_param_set);
+
+        assert_eq!(system.access().conflicts().len(), 1);
+    }
 }
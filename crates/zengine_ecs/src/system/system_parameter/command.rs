@@ -35,6 +35,17 @@ impl<T: ComponentBundle> Command for SpawnCommand<T> {
     }
 }
 
+struct SpawnBatchCommand<T: ComponentBundle> {
+    entities: Vec<Entity>,
+    bundles: Vec<T>,
+}
+
+impl<T: ComponentBundle> Command for SpawnBatchCommand<T> {
+    fn apply(self, world: &mut World) {
+        world.spawn_reserved_batch(&self.entities, self.bundles);
+    }
+}
+
 struct DespawnCommand {
     entity: Entity,
 }
@@ -147,6 +158,24 @@ impl<'a> Commands<'a> {
         entity
     }
 
+    /// Spawn a new entity for each Components tuple of the given iterator
+    ///
+    /// The entities are reserved immediately and returned in the same order of the bundles
+    pub fn spawn_batch<T, I>(&mut self, iter: I) -> Vec<Entity>
+    where
+        T: ComponentBundle,
+        I: IntoIterator<Item = T>,
+    {
+        let bundles: Vec<T> = iter.into_iter().collect();
+        let entities = self.entities.generate_batch(bundles.len());
+        self.queue.push(Box::new(SpawnBatchCommand {
+            entities: entities.clone(),
+            bundles,
+        }));
+
+        entities
+    }
+
     /// Despawn the given [Entity]
     pub fn despawn(&mut self, entity: Entity) {
         self.queue.push(Box::new(DespawnCommand { entity }))
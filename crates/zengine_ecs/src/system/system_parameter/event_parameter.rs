@@ -1,6 +1,7 @@
 use super::{SystemParam, SystemParamFetch};
 use crate::{
     event::{EventHandler, SubscriptionToken},
+    system::{AccessTarget, SystemAccess},
     world::World,
 };
 use std::{
@@ -53,7 +54,9 @@ impl<E: Any + std::fmt::Debug> Default for EventStreamState<E> {
 impl<'a, E: Any + std::fmt::Debug> SystemParamFetch<'a> for EventStreamState<E> {
     type Item = EventStream<'a, E>;
 
-    fn init(&mut self, world: &mut World) {
+    fn init(&mut self, world: &mut World, access: &mut SystemAccess) {
+        access.add_read::<E>(AccessTarget::Event);
+
         if world.get_event_handler::<E>().is_none() {
             world.create_event_handler::<E>()
         }
@@ -116,7 +119,9 @@ impl<E: Any + std::fmt::Debug> Default for EventState<E> {
 impl<'a, E: Any + std::fmt::Debug> SystemParamFetch<'a> for EventState<E> {
     type Item = Event<'a, E>;
 
-    fn init(&mut self, world: &mut World) {
+    fn init(&mut self, world: &mut World, access: &mut SystemAccess) {
+        access.add_read::<E>(AccessTarget::Event);
+
         if world.get_event_handler::<E>().is_none() {
             world.create_event_handler::<E>()
         }
@@ -176,7 +181,9 @@ impl<E: Any + std::fmt::Debug> Default for EventPublisherState<E> {
 impl<'a, E: Any + std::fmt::Debug> SystemParamFetch<'a> for EventPublisherState<E> {
     type Item = EventPublisher<'a, E>;
 
-    fn init(&mut self, world: &mut World) {
+    fn init(&mut self, world: &mut World, access: &mut SystemAccess) {
+        access.add_write::<E>(AccessTarget::Event);
+
         if world.get_event_handler::<E>().is_none() {
             world.create_event_handler::<E>()
         }
@@ -1,14 +1,16 @@
-use crate::world::World;
+use crate::{system::SystemAccess, world::World};
 
 mod command;
 mod event_parameter;
 mod local_parameter;
+mod param_set;
 mod query_parameter;
 mod res_parameter;
 
 pub use command::*;
 pub use event_parameter::*;
 pub use local_parameter::*;
+pub use param_set::*;
 pub use query_parameter::*;
 pub use res_parameter::*;
 
@@ -21,7 +23,7 @@ pub trait SystemParam: Sized {
 pub trait SystemParamFetch<'a> {
     type Item;
 
-    fn init(&mut self, _world: &mut World) {}
+    fn init(&mut self, _world: &mut World, _access: &mut SystemAccess) {}
 
     fn fetch(&'a mut self, world: &'a World) -> Self::Item;
 
@@ -0,0 +1,133 @@
+use super::{SystemParam, SystemParamFetch, SystemParamItem};
+use crate::{system::SystemAccess, World};
+
+/// A set of system parameters that could conflict with each other
+///
+/// Only one parameter of the set can be used at a time so it's possible
+/// to have, for example, two queries that access mutably the same component
+/// in the same system.
+///
+/// Each parameter of the set is accessible using the methods `p0`, `p1`, ... `p7`
+///
+/// # Example
+/// ```
+/// use zengine_macro::Component;
+/// use zengine_ecs::{
+///     query::{Query, QueryIter, QueryIterMut},
+///     system::ParamSet,
+/// };
+///
+/// #[derive(Component, Debug)]
+/// struct Position(f32);
+///
+/// fn my_system(mut set: ParamSet<(Query<(&mut Position,)>, Query<(&Position,)>)>) {
+///     for position in set.p0().iter_mut() {
+///         position.0 += 1.;
+///     }
+///
+///     for position in set.p1().iter() {
+///         println!("Position {:?}", position);
+///     }
+/// }
+/// ```
+pub struct ParamSet<'a, T: SystemParam> {
+    state: &'a mut T::Fetch,
+    world: &'a World,
+}
+
+#[doc(hidden)]
+pub struct ParamSetState<T: SystemParam> {
+    state: T::Fetch,
+}
+
+impl<T: SystemParam> Default for ParamSetState<T> {
+    fn default() -> Self {
+        Self {
+            state: T::Fetch::default(),
+        }
+    }
+}
+
+macro_rules! impl_param_set {
+    ($(($method: ident, $param: ident, $index: tt)),+) => {
+        impl<'a, $($param: SystemParam),*> ParamSet<'a, ($($param,)*)> {
+            $(
+                /// Returns the parameter of the set at the position of the method name
+                pub fn $method(&mut self) -> SystemParamItem<'_, $param> {
+                    self.state.$index.fetch(self.world)
+                }
+            )*
+        }
+
+        #[allow(non_snake_case)]
+        impl<'a, $($param: SystemParam + 'static),*> SystemParamFetch<'a> for ParamSetState<($($param,)*)> {
+            type Item = ParamSet<'a, ($($param,)*)>;
+
+            fn init(&mut self, world: &mut World, access: &mut SystemAccess) {
+                let ($($param,)*) = &mut self.state;
+
+                let mut sets = Vec::default();
+                $(
+                    let mut set = SystemAccess::default();
+                    $param.init(world, &mut set);
+                    sets.push(set);
+                )*
+
+                access.merge_disjoint(sets);
+            }
+
+            fn fetch(&'a mut self, world: &'a World) -> Self::Item {
+                ParamSet {
+                    state: &mut self.state,
+                    world,
+                }
+            }
+
+            fn apply(&mut self, world: &mut World) {
+                self.state.apply(world);
+            }
+        }
+
+        impl<'a, $($param: SystemParam + 'static),*> SystemParam for ParamSet<'a, ($($param,)*)> {
+            type Fetch = ParamSetState<($($param,)*)>;
+        }
+    };
+}
+
+impl_param_set!((p0, P0, 0), (p1, P1, 1));
+impl_param_set!((p0, P0, 0), (p1, P1, 1), (p2, P2, 2));
+impl_param_set!((p0, P0, 0), (p1, P1, 1), (p2, P2, 2), (p3, P3, 3));
+impl_param_set!(
+    (p0, P0, 0),
+    (p1, P1, 1),
+    (p2, P2, 2),
+    (p3, P3, 3),
+    (p4, P4, 4)
+);
+impl_param_set!(
+    (p0, P0, 0),
+    (p1, P1, 1),
+    (p2, P2, 2),
+    (p3, P3, 3),
+    (p4, P4, 4),
+    (p5, P5, 5)
+);
+impl_param_set!(
+    (p0, P0, 0),
+    (p1, P1, 1),
+    (p2, P2, 2),
+    (p3, P3, 3),
+    (p4, P4, 4),
+    (p5, P5, 5),
+    (p6, P6, 6)
+);
+impl_param_set!(
+    (p0, P0, 0),
+    (p1, P1, 1),
+    (p2, P2, 2),
+    (p3, P3, 3),
+    (p4, P4, 4),
+    (p5, P5, 5),
+    (p6, P6, 6),
+    (p7, P7, 7)
+);
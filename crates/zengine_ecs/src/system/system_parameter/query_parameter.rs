@@ -1,6 +1,7 @@
 use super::{SystemParam, SystemParamFetch};
 use crate::{
     query::{Query, QueryParameters, QueryRunner},
+    system::SystemAccess,
     World,
 };
 
@@ -20,6 +21,10 @@ impl<T: QueryParameters> Default for QueryState<T> {
 impl<'a, T: QueryParameters> SystemParamFetch<'a> for QueryState<T> {
     type Item = Query<'a, T>;
 
+    fn init(&mut self, _world: &mut World, access: &mut SystemAccess) {
+        T::access(access);
+    }
+
     fn fetch(&mut self, world: &'a World) -> Self::Item {
         self.query_runner.run(world)
     }
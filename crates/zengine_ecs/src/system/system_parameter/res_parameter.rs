@@ -4,7 +4,10 @@ use std::{
     sync::{RwLockReadGuard, RwLockWriteGuard},
 };
 
-use crate::{Resource, UnsendableResource, World};
+use crate::{
+    system::{AccessTarget, SystemAccess},
+    Resource, UnsendableResource, World,
+};
 
 use super::{SystemParam, SystemParamFetch};
 
@@ -49,7 +52,9 @@ impl<T: Resource + Default> Default for ResState<T> {
 impl<'a, R: Resource + Default> SystemParamFetch<'a> for ResState<R> {
     type Item = Res<'a, R>;
 
-    fn init(&mut self, world: &mut World) {
+    fn init(&mut self, world: &mut World, access: &mut SystemAccess) {
+        access.add_read::<R>(AccessTarget::Resource);
+
         if world.get_resource::<R>().is_none() {
             world.create_resource(R::default())
         }
@@ -112,7 +117,9 @@ impl<T: Resource + Default> Default for ResMutState<T> {
 impl<'a, R: Resource + Default> SystemParamFetch<'a> for ResMutState<R> {
     type Item = ResMut<'a, R>;
 
-    fn init(&mut self, world: &mut World) {
+    fn init(&mut self, world: &mut World, access: &mut SystemAccess) {
+        access.add_write::<R>(AccessTarget::Resource);
+
         if world.get_resource::<R>().is_none() {
             world.create_resource::<R>(R::default())
         }
@@ -143,6 +150,10 @@ impl<T: Resource> Default for OptionalResState<T> {
 impl<'a, R: Resource> SystemParamFetch<'a> for OptionalResState<R> {
     type Item = Option<Res<'a, R>>;
 
+    fn init(&mut self, _world: &mut World, access: &mut SystemAccess) {
+        access.add_read::<R>(AccessTarget::Resource);
+    }
+
     fn fetch(&mut self, world: &'a World) -> Self::Item {
         world.get_resource()
     }
@@ -168,6 +179,10 @@ impl<T: Resource> Default for OptionalResMutState<T> {
 impl<'a, R: Resource> SystemParamFetch<'a> for OptionalResMutState<R> {
     type Item = Option<ResMut<'a, R>>;
 
+    fn init(&mut self, _world: &mut World, access: &mut SystemAccess) {
+        access.add_write::<R>(AccessTarget::Resource);
+    }
+
     fn fetch(&mut self, world: &'a World) -> Self::Item {
         world.get_mut_resource()
     }
@@ -221,7 +236,9 @@ impl<T: UnsendableResource + Default> Default for UnsendableResState<T> {
 impl<'a, R: UnsendableResource + Default> SystemParamFetch<'a> for UnsendableResState<R> {
     type Item = UnsendableRes<'a, R>;
 
-    fn init(&mut self, world: &mut World) {
+    fn init(&mut self, world: &mut World, access: &mut SystemAccess) {
+        access.add_read::<R>(AccessTarget::UnsendableResource);
+
         if world.get_unsendable_resource::<R>().is_none() {
             world.create_unsendable_resource(R::default());
         }
@@ -284,7 +301,9 @@ impl<T: UnsendableResource + Default> Default for UnsendableResMutState<T> {
 impl<'a, R: UnsendableResource + Default> SystemParamFetch<'a> for UnsendableResMutState<R> {
     type Item = UnsendableResMut<'a, R>;
 
-    fn init(&mut self, world: &mut World) {
+    fn init(&mut self, world: &mut World, access: &mut SystemAccess) {
+        access.add_write::<R>(AccessTarget::UnsendableResource);
+
         if world.get_unsendable_resource::<R>().is_none() {
             world.create_unsendable_resource(R::default())
         }
@@ -315,6 +334,10 @@ impl<T: UnsendableResource> Default for OptionalUnsendableResState<T> {
 impl<'a, R: UnsendableResource> SystemParamFetch<'a> for OptionalUnsendableResState<R> {
     type Item = Option<UnsendableRes<'a, R>>;
 
+    fn init(&mut self, _world: &mut World, access: &mut SystemAccess) {
+        access.add_read::<R>(AccessTarget::UnsendableResource);
+    }
+
     fn fetch(&mut self, world: &'a World) -> Self::Item {
         world.get_unsendable_resource()
     }
@@ -340,6 +363,10 @@ impl<T: UnsendableResource> Default for OptionalUnsendableResMutState<T> {
 impl<'a, R: UnsendableResource> SystemParamFetch<'a> for OptionalUnsendableResMutState<R> {
     type Item = Option<UnsendableResMut<'a, R>>;
 
+    fn init(&mut self, _world: &mut World, access: &mut SystemAccess) {
+        access.add_write::<R>(AccessTarget::UnsendableResource);
+    }
+
     fn fetch(&mut self, world: &'a World) -> Self::Item {
         world.get_mut_unsendable_resource()
     }
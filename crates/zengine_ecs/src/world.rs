@@ -4,17 +4,20 @@ use std::{
     collections::HashMap,
     fmt::Debug,
     hash::BuildHasherDefault,
-    sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 
 use nohash_hasher::NoHashHasher;
 use rustc_hash::FxHashMap;
 
 use crate::{
-    archetype::{calculate_archetype_id, Archetype, ArchetypeSpecs},
+    archetype::{
+        calculate_archetype_id, AddEdge, Archetype, ArchetypeInfo, ArchetypeSpecs, BundleColumn,
+        RemoveEdge,
+    },
     component::{ComponentBundle, ComponentColumn, InsertType},
     entity::{Entity, EntityGenerator},
-    event::{EventCell, EventHandler},
+    event::{EventCell, EventHandler, EventHandlerInfo},
     query::{QueryParameters, QueryRunner},
     resource::{Resource, ResourceCell, UnsendableResource, UnsendableResourceCell},
 };
@@ -128,6 +131,84 @@ impl World {
         self.add_component(entity, component_bundle);
     }
 
+    /// Creates an [Entity] for each component bundle of the given iterator
+    ///
+    /// The destination archetype is resolved only once and the storage for
+    /// all the entities is reserved in advance, so this is faster than
+    /// calling [World::spawn] in a loop.
+    /// Returns the spawned entities in the same order of the bundles
+    ///
+    /// # Example
+    /// ```
+    /// use zengine_macro::Component;
+    /// use zengine_ecs::World;
+    ///
+    /// #[derive(Component, Debug)]
+    /// struct Bullet {
+    ///     speed: f32,
+    /// }
+    ///
+    /// let mut world = World::default();
+    /// let bullets = world.spawn_batch((0..100).map(|_| Bullet { speed: 5. }));
+    ///
+    /// assert_eq!(bullets.len(), 100);
+    /// ```
+    pub fn spawn_batch<T, I>(&mut self, iter: I) -> Vec<Entity>
+    where
+        T: ComponentBundle,
+        I: IntoIterator<Item = T>,
+    {
+        let bundles: Vec<T> = iter.into_iter().collect();
+        let entities = self.entity_generator.generate_batch(bundles.len());
+
+        self.spawn_reserved_batch(&entities, bundles);
+
+        entities
+    }
+
+    pub(crate) fn spawn_reserved_batch<T: ComponentBundle>(
+        &mut self,
+        entities: &[Entity],
+        bundles: Vec<T>,
+    ) {
+        let edge = self.add_edge::<T>(0);
+        let archetype_index = edge.destination;
+
+        let archetype = self
+            .archetypes
+            .get_mut(archetype_index)
+            .expect("destination archetype should be present");
+
+        let first_row = archetype.entities.len();
+        archetype.entities.extend_from_slice(entities);
+        for column in archetype.components.iter_mut() {
+            column.reserve(entities.len());
+        }
+
+        self.entity_record.reserve(entities.len());
+        for (offset, (entity, bundle)) in entities.iter().zip(bundles).enumerate() {
+            let row = first_row + offset;
+            bundle.inser_into(
+                archetype,
+                edge.columns
+                    .iter()
+                    .map(|column| match column {
+                        BundleColumn::Add(index) => (InsertType::Add, *index),
+                        BundleColumn::Replace(index) => (InsertType::Replace(row), *index),
+                    })
+                    .collect(),
+            );
+
+            self.entity_record.insert(
+                *entity,
+                Record {
+                    archetype_index,
+                    row,
+                },
+            );
+        }
+    }
+
     fn internal_spawn(&mut self) -> Entity {
         let entity = self.entity_generator.generate();
 
@@ -147,6 +228,26 @@ impl World {
         entity
     }
 
+    /// Returns `true` if the given [Entity] exists in the World
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entity_record.contains_key(&entity)
+    }
+
+    /// Returns the number of entities in the World
+    pub fn entity_count(&self) -> usize {
+        self.entity_record.len()
+    }
+
+    /// Returns the component list and the number of entities of each archetype
+    pub fn archetypes_info(&self) -> Vec<ArchetypeInfo> {
+        self.archetypes.iter().map(|a| a.info()).collect()
+    }
+
+    /// Returns the buffer sizes of each [EventHandler]
+    pub fn event_handlers_info(&self) -> Vec<EventHandlerInfo> {
+        self.event_handlers.values().map(|e| e.info()).collect()
+    }
+
     /// Removes an Entity from the World
     pub fn despawn(&mut self, entity: Entity) {
         if let Some(record) = self.entity_record.get(&entity) {
@@ -176,196 +277,189 @@ impl World {
 
     /// Adds a component or a tuple of components to an Entity
     pub fn add_component<T: ComponentBundle>(&mut self, entity: Entity, component_bundle: T) {
-        let component_ids = T::get_types();
-
         if let Some(record) = self.entity_record.get(&entity) {
-            let archetype = self
-                .archetypes
-                .get(record.archetype_index)
-                .expect("archetype should be present");
+            let source_archetype = record.archetype_index;
+            let source_row = record.row;
 
-            let mut destination_archetype_specs = archetype.archetype_specs.clone();
-            let mut new_archetype = false;
+            let edge = self.add_edge::<T>(source_archetype);
 
-            for c_id in component_ids.iter() {
-                if let Err(insert_index) = destination_archetype_specs.binary_search(c_id) {
-                    destination_archetype_specs.insert(insert_index, *c_id);
-                    new_archetype = true;
-                }
-            }
+            if edge.destination == source_archetype {
+                component_bundle.inser_into(
+                    self.archetypes
+                        .get_mut(source_archetype)
+                        .expect("target archetype should be present"),
+                    edge.columns
+                        .iter()
+                        .filter_map(|column| match column {
+                            BundleColumn::Replace(index) => {
+                                Some((InsertType::Replace(source_row), *index))
+                            }
+                            _ => None,
+                        })
+                        .collect(),
+                );
 
-            enum ColumnType {
-                Add(usize),
-                Replace(usize),
-            }
-            let mut columns: Vec<ColumnType> = Vec::default();
-            for c_type in component_ids.iter() {
-                match (
-                    archetype.archetype_specs.iter().position(|c| c == c_type),
-                    destination_archetype_specs.iter().position(|c| c == c_type),
-                ) {
-                    (Some(_), Some(new_index)) => {
-                        // replace
-                        columns.push(ColumnType::Replace(new_index))
-                    }
-                    (None, Some(new_index)) => {
-                        // add
-                        columns.push(ColumnType::Add(new_index));
-                    }
-                    _ => {}
-                }
+                return;
             }
 
-            if new_archetype {
-                let source_archetype = record.archetype_index;
-                let source_row = record.row;
-
-                let destination_archetype_id = calculate_archetype_id(&destination_archetype_specs);
-
-                let destination_archetype_index = self
-                    .archetype_map
-                    .get(&destination_archetype_id)
-                    .copied()
-                    .unwrap_or_else(|| {
-                        let destination_archetype_index = self.archetypes.len();
-                        let mut component_columns: Vec<(TypeId, Box<dyn ComponentColumn>)> = self
-                            .archetypes
-                            .get(source_archetype)
-                            .expect("source archetype should be present")
-                            .components
-                            .iter()
-                            .map(|column| column.new_same_type())
-                            .collect();
-
-                        component_columns.append(
-                            &mut T::get_component_columns()
-                                .into_iter()
-                                .enumerate()
-                                .filter_map(|(index, c)| {
-                                    if let Some(ColumnType::Add(_)) = columns.get(index) {
-                                        Some(c)
-                                    } else {
-                                        None
-                                    }
-                                })
-                                .collect(),
-                        );
-
-                        self.archetypes.push(Archetype::new_from_component(
-                            destination_archetype_specs,
-                            component_columns,
-                        ));
-                        self.archetype_map
-                            .insert(destination_archetype_id, destination_archetype_index);
-
-                        destination_archetype_index
-                    });
-
-                // migrate component to the new archetype
-
-                // index_twice lets us mutably borrow from the world twice.
-                let (old_archetype, new_archetype) = index_twice(
-                    &mut self.archetypes,
-                    source_archetype,
-                    destination_archetype_index,
-                );
-
-                old_archetype.entities.swap_remove(source_row);
-                new_archetype.entities.push(entity);
+            let new_archetype = self.migrate_entity(
+                entity,
+                source_archetype,
+                source_row,
+                edge.destination,
+                &edge.migration,
+            );
 
-                for (old_column_index, new_column_index) in new_archetype
-                    .archetype_specs
+            let new_row = new_archetype.entities.len() - 1;
+            component_bundle.inser_into(
+                new_archetype,
+                edge.columns
                     .iter()
-                    .enumerate()
-                    .filter_map(|(index, c1)| {
-                        if old_archetype.archetype_specs.iter().any(|c2| c1 == c2) {
-                            Some(index)
-                        } else {
-                            None
-                        }
+                    .map(|column| match column {
+                        BundleColumn::Add(index) => (InsertType::Add, *index),
+                        BundleColumn::Replace(index) => (InsertType::Replace(new_row), *index),
                     })
-                    .enumerate()
-                    .collect::<Vec<(usize, usize)>>()
-                {
-                    old_archetype.migrate_component(
-                        old_column_index,
-                        source_row,
-                        new_archetype,
-                        new_column_index,
-                    );
-                }
+                    .collect(),
+            );
+        }
+    }
 
-                let new_row = new_archetype.entities.len() - 1;
-                component_bundle.inser_into(
-                    new_archetype,
-                    columns
-                        .into_iter()
-                        .map(|column| match column {
-                            ColumnType::Add(column_index) => (InsertType::Add, column_index),
-                            ColumnType::Replace(new_index) => {
-                                (InsertType::Replace(new_row), new_index)
-                            }
-                        })
-                        .collect(),
+    /// Removes a component or a tuple of components from an Entity
+    pub fn remove_component<T: ComponentBundle>(&mut self, entity: Entity) {
+        if let Some(record) = self.entity_record.get(&entity) {
+            let source_archetype = record.archetype_index;
+            let source_row = record.row;
+
+            let edge = self.remove_edge::<T>(source_archetype);
+
+            if edge.destination != source_archetype {
+                self.migrate_entity(
+                    entity,
+                    source_archetype,
+                    source_row,
+                    edge.destination,
+                    &edge.migration,
                 );
+            }
+        }
+    }
 
-                // component migrated
+    /// Returns the cached edge that adds the bundle `T` to the source archetype
+    /// computing it the first time
+    fn add_edge<T: ComponentBundle>(&mut self, source_archetype: usize) -> Arc<AddEdge> {
+        let bundle_id = TypeId::of::<T>();
+        let archetype = self
+            .archetypes
+            .get(source_archetype)
+            .expect("archetype should be present");
 
-                // update entity reference
+        if let Some(edge) = archetype.edges.add.get(&bundle_id) {
+            return edge.clone();
+        }
 
-                // get the entity that take the place of the old one
-                if let Some(record) = old_archetype
-                    .entities
-                    .get(record.row)
-                    .and_then(|entity| self.entity_record.get_mut(entity))
-                {
-                    record.row = source_row
-                }
+        let component_ids = T::get_types();
 
-                if let Some(record) = self.entity_record.get_mut(&entity) {
-                    record.archetype_index = destination_archetype_index;
-                    record.row = new_row;
-                }
-            } else {
-                component_bundle.inser_into(
-                    self.archetypes
-                        .get_mut(record.archetype_index)
-                        .expect("target archetype should be present"),
-                    columns
+        let mut destination_archetype_specs = archetype.archetype_specs.clone();
+        let mut new_archetype = false;
+
+        for c_id in component_ids.iter() {
+            if let Err(insert_index) = destination_archetype_specs.binary_search(c_id) {
+                destination_archetype_specs.insert(insert_index, *c_id);
+                new_archetype = true;
+            }
+        }
+
+        let mut columns: Vec<BundleColumn> = Vec::default();
+        for c_type in component_ids.iter() {
+            match (
+                archetype.archetype_specs.iter().position(|c| c == c_type),
+                destination_archetype_specs.iter().position(|c| c == c_type),
+            ) {
+                (Some(_), Some(new_index)) => columns.push(BundleColumn::Replace(new_index)),
+                (None, Some(new_index)) => columns.push(BundleColumn::Add(new_index)),
+                _ => {}
+            }
+        }
+
+        let edge = if new_archetype {
+            let migration = archetype
+                .archetype_specs
+                .iter()
+                .enumerate()
+                .filter_map(|(old_index, c)| {
+                    destination_archetype_specs
+                        .binary_search(c)
+                        .ok()
+                        .map(|new_index| (old_index, new_index))
+                })
+                .collect();
+
+            let destination = self.get_or_create_archetype(destination_archetype_specs, |world| {
+                let mut component_columns: Vec<(TypeId, Box<dyn ComponentColumn>)> = world
+                    .archetypes
+                    .get(source_archetype)
+                    .expect("source archetype should be present")
+                    .components
+                    .iter()
+                    .map(|column| column.new_same_type())
+                    .collect();
+
+                component_columns.append(
+                    &mut T::get_component_columns()
                         .into_iter()
-                        .filter_map(|column| match column {
-                            ColumnType::Replace(new_index) => {
-                                Some((InsertType::Replace(record.row), new_index))
+                        .enumerate()
+                        .filter_map(|(index, c)| {
+                            if let Some(BundleColumn::Add(_)) = columns.get(index) {
+                                Some(c)
+                            } else {
+                                None
                             }
-                            _ => None,
                         })
                         .collect(),
                 );
+
+                component_columns
+            });
+
+            AddEdge {
+                destination,
+                columns,
+                migration,
             }
-        }
+        } else {
+            AddEdge {
+                destination: source_archetype,
+                columns,
+                migration: Vec::default(),
+            }
+        };
+
+        let edge = Arc::new(edge);
+        self.archetypes[source_archetype]
+            .edges
+            .add
+            .insert(bundle_id, edge.clone());
+
+        edge
     }
 
-    /// Removes a component or a tuple of components from an Entity
-    pub fn remove_component<T: ComponentBundle>(&mut self, entity: Entity) {
-        let component_ids = T::get_types();
+    /// Returns the cached edge that removes the bundle `T` from the source archetype
+    /// computing it the first time
+    fn remove_edge<T: ComponentBundle>(&mut self, source_archetype: usize) -> Arc<RemoveEdge> {
+        let bundle_id = TypeId::of::<T>();
+        let archetype = self
+            .archetypes
+            .get(source_archetype)
+            .expect("archetype should be present");
 
-        if let Some(record) = self.entity_record.get(&entity) {
-            let archetype = self
-                .archetypes
-                .get(record.archetype_index)
-                .expect("archetype should be present");
+        if let Some(edge) = archetype.edges.remove.get(&bundle_id) {
+            return edge.clone();
+        }
 
-            let mut column_indexes: Vec<usize> = Vec::default();
+        let component_ids = T::get_types();
 
-            for c_id in component_ids.iter() {
-                if let Ok(index) = archetype.archetype_specs.binary_search(c_id) {
-                    column_indexes.push(index)
-                }
-            }
-            let (migrate_column_indexes, destination_archetype_specs): (
-                Vec<usize>,
-                ArchetypeSpecs,
-            ) = archetype
+        let (migrate_column_indexes, destination_archetype_specs): (Vec<usize>, ArchetypeSpecs) =
+            archetype
                 .archetype_specs
                 .iter()
                 .enumerate()
@@ -373,81 +467,119 @@ impl World {
                 .map(|(index, c)| (index, *c))
                 .unzip();
 
-            let source_archetype = record.archetype_index;
-            let source_row = record.row;
+        let edge = if destination_archetype_specs.len() == archetype.archetype_specs.len() {
+            RemoveEdge {
+                destination: source_archetype,
+                migration: Vec::default(),
+            }
+        } else {
+            let destination = self.get_or_create_archetype(destination_archetype_specs, |world| {
+                world
+                    .archetypes
+                    .get(source_archetype)
+                    .expect("source archetype should be present")
+                    .components
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| migrate_column_indexes.contains(index))
+                    .map(|(_, column)| column.new_same_type())
+                    .collect()
+            });
+
+            RemoveEdge {
+                destination,
+                migration: migrate_column_indexes
+                    .into_iter()
+                    .enumerate()
+                    .map(|(new_index, old_index)| (old_index, new_index))
+                    .collect(),
+            }
+        };
 
-            let destination_archetype_id = calculate_archetype_id(&destination_archetype_specs);
-
-            let destination_archetype_index = self
-                .archetype_map
-                .get(&destination_archetype_id)
-                .copied()
-                .unwrap_or_else(|| {
-                    let destination_archetype_index = self.archetypes.len();
-                    let component_columns: Vec<(TypeId, Box<dyn ComponentColumn>)> = self
-                        .archetypes
-                        .get(source_archetype)
-                        .expect("source archetype should be present")
-                        .components
-                        .iter()
-                        .enumerate()
-                        .filter(|(index, _)| migrate_column_indexes.iter().any(|c| c == index))
-                        .map(|(_, column)| column.new_same_type())
-                        .collect();
+        let edge = Arc::new(edge);
+        self.archetypes[source_archetype]
+            .edges
+            .remove
+            .insert(bundle_id, edge.clone());
 
-                    self.archetypes.push(Archetype::new_from_component(
-                        destination_archetype_specs,
-                        component_columns,
-                    ));
-                    self.archetype_map
-                        .insert(destination_archetype_id, destination_archetype_index);
+        edge
+    }
 
-                    destination_archetype_index
-                });
+    fn get_or_create_archetype<F>(&mut self, archetype_specs: ArchetypeSpecs, columns: F) -> usize
+    where
+        F: FnOnce(&World) -> Vec<(TypeId, Box<dyn ComponentColumn>)>,
+    {
+        let archetype_id = calculate_archetype_id(&archetype_specs);
 
-            // migrate component to the new archetype
+        if let Some(archetype_index) = self.archetype_map.get(&archetype_id) {
+            return *archetype_index;
+        }
 
-            // index_twice lets us mutably borrow from the world twice.
-            let (old_archetype, new_archetype) = index_twice(
-                &mut self.archetypes,
-                source_archetype,
-                destination_archetype_index,
-            );
+        let archetype_index = self.archetypes.len();
+        let component_columns = columns(self);
+        self.archetypes.push(Archetype::new_from_component(
+            archetype_specs,
+            component_columns,
+        ));
+        self.archetype_map.insert(archetype_id, archetype_index);
 
-            old_archetype.entities.swap_remove(source_row);
-            new_archetype.entities.push(entity);
+        archetype_index
+    }
 
-            for (column_index, _) in migrate_column_indexes
-                .iter()
-                .enumerate()
-                .take(new_archetype.components.len())
-            {
-                old_archetype.migrate_component(
-                    migrate_column_indexes[column_index],
-                    source_row,
-                    new_archetype,
-                    column_index,
-                );
-            }
+    /// Moves an entity and the migrated components to the destination archetype
+    /// and returns it
+    fn migrate_entity(
+        &mut self,
+        entity: Entity,
+        source_archetype: usize,
+        source_row: usize,
+        destination_archetype: usize,
+        migration: &[(usize, usize)],
+    ) -> &mut Archetype {
+        // index_twice lets us mutably borrow from the world twice.
+        let (old_archetype, new_archetype) = index_twice(
+            &mut self.archetypes,
+            source_archetype,
+            destination_archetype,
+        );
 
-            // component migrated
+        old_archetype.entities.swap_remove(source_row);
+        new_archetype.entities.push(entity);
 
-            // update entity reference
+        for (old_column_index, new_column_index) in migration {
+            old_archetype.migrate_component(
+                *old_column_index,
+                source_row,
+                new_archetype,
+                *new_column_index,
+            );
+        }
 
-            // get the entity that take the place of the old one
-            if let Some(record) = old_archetype
-                .entities
-                .get(record.row)
-                .and_then(|entity| self.entity_record.get_mut(entity))
+        // drop the components that are not migrated
+        for (column_index, column) in old_archetype.components.iter_mut().enumerate() {
+            if !migration
+                .iter()
+                .any(|(old_index, _)| *old_index == column_index)
             {
-                record.row = source_row;
+                column.swap_remove(source_row);
             }
+        }
 
-            if let Some(record) = self.entity_record.get_mut(&entity) {
-                record.archetype_index = destination_archetype_index;
-                record.row = new_archetype.entities.len() - 1;
-            }
+        // get the entity that take the place of the old one
+        if let Some(record) = old_archetype
+            .entities
+            .get(source_row)
+            .and_then(|entity| self.entity_record.get_mut(entity))
+        {
+            record.row = source_row;
         }
+
+        if let Some(record) = self.entity_record.get_mut(&entity) {
+            record.archetype_index = destination_archetype;
+            record.row = new_archetype.entities.len() - 1;
+        }
+
+        new_archetype
     }
 
     /// Queries entity and components from the World
@@ -1017,4 +1149,102 @@ mod tests {
             assert_eq!(res.data, 7);
         }
     }
+
+    #[test]
+    fn add_and_remove_edges_are_cached() {
+        let mut world = World::default();
+
+        let entity1 = world.spawn(Component1 {});
+        let entity2 = world.spawn(Component1 {});
+
+        world.add_component(entity1, Component3 { data: 1 });
+        let archetypes_count = world.archetypes.len();
+        world.add_component(entity2, Component3 { data: 2 });
+
+        assert_eq!(world.archetypes.len(), archetypes_count);
+        let source = world.archetypes.get(1).unwrap();
+        let destination_index = source
+            .edges
+            .add
+            .get(&TypeId::of::<Component3>())
+            .unwrap()
+            .destination;
+        assert_eq!(
+            world.entity_record.get(&entity2).unwrap().archetype_index,
+            destination_index
+        );
+
+        world.remove_component::<Component3>(entity1);
+        let destination = world.archetypes.get(destination_index).unwrap();
+        assert!(destination
+            .edges
+            .remove
+            .contains_key(&TypeId::of::<Component3>()));
+        assert_eq!(
+            world.entity_record.get(&entity1).unwrap().archetype_index,
+            1
+        );
+    }
+
+    #[test]
+    fn remove_component_keeps_other_rows_aligned() {
+        let mut world = World::default();
+
+        let entity1 = world.spawn((Component1 {}, Component3 { data: 1 }));
+        world.spawn((Component1 {}, Component3 { data: 2 }));
+
+        world.remove_component::<Component3>(entity1);
+
+        let mut query = world.query::<(&Component3,)>();
+        let query = query.run(&world);
+        let data: Vec<u32> = crate::query::QueryIter::iter(&query)
+            .map(|c| c.data)
+            .collect();
+
+        assert_eq!(data, vec![2]);
+    }
+
+    #[test]
+    fn remove_missing_component() {
+        let mut world = World::default();
+
+        let entity = world.spawn(Component1 {});
+        world.remove_component::<Component3>(entity);
+
+        assert_eq!(
+            world.entity_record.get(&entity),
+            Some(&Record {
+                archetype_index: 1,
+                row: 0
+            })
+        );
+    }
+
+    #[test]
+    fn spawn_batch() {
+        let mut world = World::default();
+
+        let entity = world.spawn((Component1 {}, Component3 { data: 0 }));
+        let entities = world.spawn_batch((1..4).map(|data| (Component3 { data }, Component1 {})));
+
+        assert_eq!(entities.len(), 3);
+        assert_eq!(world.archetypes.len(), 2);
+        for (row, entity) in std::iter::once(entity).chain(entities).enumerate() {
+            assert_eq!(
+                world.entity_record.get(&entity),
+                Some(&Record {
+                    archetype_index: 1,
+                    row
+                })
+            );
+        }
+
+        let mut query = world.query::<(&Component3,)>();
+        let query = query.run(&world);
+        let data: Vec<u32> = crate::query::QueryIter::iter(&query)
+            .map(|c| c.data)
+            .collect();
+
+        assert_eq!(data, vec![0, 1, 2, 3]);
+    }
 }
@@ -0,0 +1,232 @@
+use std::{fmt, time::Duration};
+
+use instant::Instant;
+use log::info;
+use zengine_ecs::{
+    event::EventHandlerInfo,
+    system::{Local, Res},
+    ArchetypeInfo,
+};
+use zengine_macro::Resource;
+
+use crate::{Engine, Module, Stage};
+
+/// Run time of a single system during the last frame
+#[derive(Debug, Clone)]
+pub struct SystemDiagnostic {
+    pub name: &'static str,
+    pub run_time: Duration,
+    /// The last frame in which the system has been executed
+    pub last_run: Option<u64>,
+}
+
+/// Run times of the systems of a [Stage] during the last frame
+#[derive(Debug, Clone)]
+pub struct StageDiagnostic {
+    pub stage: Stage,
+    pub systems: Vec<SystemDiagnostic>,
+}
+
+impl StageDiagnostic {
+    /// Returns the sum of the run times of the stage systems
+    pub fn run_time(&self) -> Duration {
+        self.systems.iter().map(|s| s.run_time).sum()
+    }
+}
+
+/// A [Resource](zengine_ecs::Resource) that contains runtime information
+/// about the [World](zengine_ecs::World) and the engine pipeline
+///
+/// It's updated at the end of each frame when the [DiagnosticModule] is added to the engine
+#[derive(Resource, Debug, Default)]
+pub struct Diagnostics {
+    /// The frame described by the diagnostics
+    pub frame: u64,
+    /// Number of entities in the World
+    pub entity_count: usize,
+    /// Components and number of entities of each archetype
+    pub archetypes: Vec<ArchetypeInfo>,
+    /// Buffer size of each event handler
+    pub event_handlers: Vec<EventHandlerInfo>,
+    /// Systems run times of each stage
+    pub stages: Vec<StageDiagnostic>,
+}
+
+impl Diagnostics {
+    /// Returns the number of archetypes in the World
+    pub fn archetype_count(&self) -> usize {
+        self.archetypes.len()
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "frame: {}, entities: {}, archetypes: {}",
+            self.frame,
+            self.entity_count,
+            self.archetype_count()
+        )?;
+        for (index, archetype) in self.archetypes.iter().enumerate() {
+            writeln!(
+                f,
+                "  archetype {}: {} rows [{}]",
+                index,
+                archetype.entities,
+                archetype.components.join(", ")
+            )?;
+        }
+        for event in self.event_handlers.iter() {
+            writeln!(
+                f,
+                "  event {}: {}/{} buffered, {} subscribers",
+                event.event, event.buffer_len, event.buffer_capacity, event.subscribers
+            )?;
+        }
+        for stage in self.stages.iter() {
+            writeln!(f, "  stage {:?}: {:?}", stage.stage, stage.run_time())?;
+            for system in stage.systems.iter() {
+                writeln!(f, "    {}: {:?}", system.name, system.run_time)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds the [Diagnostics] resource to the engine
+///
+/// When a log interval is specified the diagnostics are logged
+/// periodically using the `info` level
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use zengine_engine::{DiagnosticModule, Engine};
+///
+/// Engine::default().add_module(DiagnosticModule::default().with_log_interval(Duration::from_secs(5)));
+/// ```
+#[derive(Debug, Default)]
+pub struct DiagnosticModule {
+    log_interval: Option<Duration>,
+}
+
+impl DiagnosticModule {
+    /// Logs the diagnostics each time the given interval is elapsed
+    pub fn with_log_interval(mut self, interval: Duration) -> Self {
+        self.log_interval = Some(interval);
+        self
+    }
+}
+
+impl Module for DiagnosticModule {
+    fn init(self, engine: &mut Engine) {
+        engine.diagnostics_enabled = true;
+        engine.world.create_resource(Diagnostics::default());
+
+        if let Some(interval) = self.log_interval {
+            engine.add_system_into_stage(log_diagnostics_system(interval), Stage::PostRender);
+        }
+    }
+}
+
+impl Engine {
+    pub(crate) fn update_diagnostics(&mut self, frame: u64) {
+        let stages = self
+            .running_stages
+            .iter()
+            .map(|(stage, systems_stage)| StageDiagnostic {
+                stage: *stage,
+                systems: systems_stage
+                    .systems
+                    .iter()
+                    .map(|s| SystemDiagnostic {
+                        name: s.system.name(),
+                        run_time: s.run_time,
+                        last_run: s.last_run,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let diagnostics = Diagnostics {
+            frame,
+            entity_count: self.world.entity_count(),
+            archetypes: self.world.archetypes_info(),
+            event_handlers: self.world.event_handlers_info(),
+            stages,
+        };
+
+        self.world.create_resource(diagnostics);
+    }
+}
+
+#[derive(Debug, Default)]
+struct LastLog(Option<Instant>);
+
+fn log_diagnostics_system(interval: Duration) -> impl Fn(Res<Diagnostics>, Local<LastLog>) {
+    move |diagnostics: Res<Diagnostics>, last_log: Local<LastLog>| {
+        let now = Instant::now();
+        match last_log.0 {
+            Some(last) if now.duration_since(last) < interval => {}
+            _ => {
+                info!("Diagnostics\n{}", *diagnostics);
+                last_log.0 = Some(now);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zengine_ecs::system::EventPublisher;
+    use zengine_macro::Component;
+
+    use crate::{DiagnosticModule, Diagnostics, Engine, Stage};
+
+    #[derive(Component, Debug)]
+    struct Component1;
+
+    #[derive(Debug)]
+    struct Event1;
+
+    fn publish_system(mut event: EventPublisher<Event1>) {
+        event.publish(Event1);
+    }
+
+    #[test]
+    fn collect_diagnostics() {
+        let mut engine = Engine::default();
+        engine
+            .add_module(DiagnosticModule::default())
+            .add_system(publish_system);
+
+        engine.world.spawn(Component1);
+        engine.world.spawn(Component1);
+
+        engine.startup();
+        engine.update();
+
+        let diagnostics = engine.world.get_resource::<Diagnostics>().unwrap();
+        assert_eq!(diagnostics.entity_count, 2);
+        assert!(diagnostics
+            .archetypes
+            .iter()
+            .any(|a| a.entities == 2 && a.components[0].ends_with("Component1")));
+        assert!(diagnostics
+            .event_handlers
+            .iter()
+            .any(|e| e.event.ends_with("Event1") && e.buffer_len == 1));
+
+        let update = diagnostics
+            .stages
+            .iter()
+            .find(|s| matches!(s.stage, Stage::Update))
+            .unwrap();
+        assert_eq!(update.systems.len(), 1);
+        assert!(update.systems[0].name.ends_with("publish_system"));
+        assert_eq!(update.systems[0].last_run, Some(0));
+        assert_eq!(diagnostics.frame, 0);
+    }
+}
@@ -0,0 +1,170 @@
+use std::time::Duration;
+
+use zengine_ecs::World;
+use zengine_macro::Resource;
+
+use crate::{AppExit, Engine};
+
+/// A [Resource](zengine_ecs::Resource) that replaces the real elapsed time
+/// with a fixed amount of time for each frame
+///
+/// It's created by an [HeadlessRunner] with a time step and it's used
+/// by the timing systems to simulate the time flow in a deterministic way
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct SimulatedTimeStep(pub Duration);
+
+type RunCondition = Box<dyn Fn(&World) -> bool>;
+
+/// A runner that drives the engine without a window or a GPU
+///
+/// It's useful to write integration tests of a whole [Engine]
+/// or to run a dedicated server. The runner stops when the given number of frames
+/// is reached, when the stop condition holds or when an [AppExit] is published.
+/// Then the [Shutdown Stage](crate::Stage::Shutdown) is executed
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use zengine_engine::{Engine, HeadlessRunner};
+///
+/// fn game_logic() {}
+///
+/// let mut engine = Engine::default();
+/// engine.add_system(game_logic);
+///
+/// let frames = HeadlessRunner::frames(10)
+///     .with_time_step(Duration::from_millis(16))
+///     .run(&mut engine);
+///
+/// assert_eq!(frames, 10);
+/// ```
+#[derive(Default)]
+pub struct HeadlessRunner {
+    max_frames: Option<usize>,
+    until: Option<RunCondition>,
+    time_step: Option<Duration>,
+}
+
+impl HeadlessRunner {
+    /// Creates a runner that stops after the given number of frames
+    pub fn frames(frames: usize) -> Self {
+        Self {
+            max_frames: Some(frames),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a runner that stops when the given condition holds.
+    /// The condition is checked after each frame
+    pub fn until<F: Fn(&World) -> bool + 'static>(condition: F) -> Self {
+        Self {
+            until: Some(Box::new(condition)),
+            ..Default::default()
+        }
+    }
+
+    /// Limits the number of frames executed by the runner
+    pub fn with_max_frames(mut self, frames: usize) -> Self {
+        self.max_frames = Some(frames);
+        self
+    }
+
+    /// Simulates the given amount of time for each frame instead of the real elapsed time
+    pub fn with_time_step(mut self, time_step: Duration) -> Self {
+        self.time_step = Some(time_step);
+        self
+    }
+
+    /// Starts the engine and runs it until one of the stop conditions is met.
+    /// Returns the number of frames executed
+    pub fn run(&self, engine: &mut Engine) -> usize {
+        engine.create_engine_event_handlers();
+        if let Some(time_step) = self.time_step {
+            engine.world.create_resource(SimulatedTimeStep(time_step));
+        }
+
+        engine.startup();
+
+        let mut frames = 0;
+        while self.max_frames.is_none_or(|max| frames < max) {
+            engine.update();
+            frames += 1;
+
+            if engine.exit_requested().is_some()
+                || self
+                    .until
+                    .as_ref()
+                    .is_some_and(|condition| condition(&engine.world))
+            {
+                break;
+            }
+        }
+
+        engine.shutdown();
+
+        frames
+    }
+
+    /// Converts the runner into a function that can be used with [Engine::set_runner]
+    ///
+    /// The runner function returns the published [AppExit] or [AppExit::Success]
+    /// when the engine is stopped by the runner itself
+    pub fn into_runner(self) -> impl Fn(Engine) -> AppExit {
+        move |mut engine| {
+            self.run(&mut engine);
+            engine.exit_requested().unwrap_or(AppExit::Success)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zengine_ecs::system::{EventPublisher, ResMut};
+    use zengine_macro::Resource;
+
+    use crate::{Engine, EngineEvent, HeadlessRunner};
+
+    #[derive(Resource, Debug, Default)]
+    struct Counter(u32);
+
+    fn count_system(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    fn quit_system(counter: ResMut<Counter>, mut event: EventPublisher<EngineEvent>) {
+        if counter.0 == 3 {
+            event.publish(EngineEvent::Quit);
+        }
+    }
+
+    #[test]
+    fn run_fixed_frames() {
+        let mut engine = Engine::default();
+        engine.add_system(count_system);
+
+        assert_eq!(HeadlessRunner::frames(5).run(&mut engine), 5);
+        assert_eq!(engine.world.get_resource::<Counter>().unwrap().0, 5);
+    }
+
+    #[test]
+    fn run_until_condition() {
+        let mut engine = Engine::default();
+        engine.add_system(count_system);
+
+        let frames = HeadlessRunner::until(|world| {
+            world.get_resource::<Counter>().is_some_and(|c| c.0 == 7)
+        })
+        .with_max_frames(100)
+        .run(&mut engine);
+
+        assert_eq!(frames, 7);
+    }
+
+    #[test]
+    fn stop_on_quit_event() {
+        let mut engine = Engine::default();
+        engine.add_system(count_system).add_system(quit_system);
+
+        assert_eq!(HeadlessRunner::default().run(&mut engine), 3);
+    }
+}
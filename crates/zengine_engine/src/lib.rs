@@ -1,28 +1,36 @@
-use std::{any::Any, collections::HashMap};
+use std::{any::Any, collections::HashMap, num::NonZeroU8, process::ExitCode, time::Duration};
+
+use instant::Instant;
 
 use zengine_ecs::{
     system::{IntoSystem, System, SystemParam},
     World,
 };
+use zengine_macro::Resource;
 
-pub use log;
+mod diagnostic;
+mod headless;
+mod module;
+mod sub_engine;
+mod task_pool;
 
-/// A collection of engine logics and configurations.
-///
-/// A Module configure the [`Engine`]. When the [`Engine`] registers a module,
-/// the module's [`Module::init`] function is call.
-pub trait Module {
-    /// Configures the [`Engine`] to which this module is added.
-    fn init(self, engine: &mut Engine);
-}
+pub use diagnostic::*;
+pub use headless::*;
+pub use log;
+pub use module::*;
+pub use sub_engine::*;
+pub use task_pool::*;
 
 /// The possible stages in the engine pipeline
-#[derive(Hash, Eq, PartialEq)]
+#[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
 pub enum Stage {
     /// Statup stage, runs only one time when the engine start
     Startup,
     /// Run just before the main update stage
     PreUpdate,
+    /// Run zero or more times per frame at a fixed rate,
+    /// driven by the run criteria set for this stage
+    FixedUpdate,
     /// Main stage
     Update,
     /// Run after the update stage
@@ -33,36 +41,127 @@ pub enum Stage {
     Render,
     /// Run after the render stage
     PostRender,
+    /// Shutdown stage, runs only one time when the engine stops
+    Shutdown,
+    /// A user defined stage registered using [Engine::add_stage_before]
+    /// or [Engine::add_stage_after]
+    Custom(&'static str),
+}
+
+/// The result of a stage run criteria
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShouldRun {
+    /// Run the stage one time
+    Yes,
+    /// Skip the stage
+    No,
+    /// Run the stage and then check again the run criteria
+    YesAndCheckAgain,
+}
+
+type RunCriteria = Box<dyn FnMut(&mut World) -> ShouldRun>;
+
+struct StageSystem {
+    system: Box<dyn System>,
+    frame_interval: u64,
+    last_run: Option<u64>,
+    run_time: Duration,
+}
+
+impl StageSystem {
+    fn new(system: Box<dyn System>, frame_interval: u64) -> Self {
+        Self {
+            system,
+            frame_interval,
+            last_run: None,
+            run_time: Duration::ZERO,
+        }
+    }
+
+    fn should_run(&self, frame: u64) -> bool {
+        self.frame_interval == 1
+            || self
+                .last_run
+                .is_none_or(|last_run| frame.wrapping_sub(last_run) >= self.frame_interval)
+    }
+
+    fn run(&mut self, world: &World, frame: u64) {
+        if self.should_run(frame) {
+            self.system.run(world);
+            self.last_run = Some(frame);
+        }
+    }
 }
 
 #[derive(Default)]
 struct SystemsStage {
-    systems: Vec<Box<dyn System>>,
+    systems: Vec<StageSystem>,
+    run_criteria: Option<RunCriteria>,
 }
 
 impl SystemsStage {
     pub fn init(&mut self, world: &mut World) {
-        for s in self.systems.iter_mut() {
+        for s in self.systems.iter_mut().map(|s| &mut s.system) {
             s.init(world);
+
+            if let Some(conflict) = s.access().conflicts().first() {
+                panic!(
+                    "System {} has conflicting parameters: {}. Use a ParamSet for parameters that are not used at the same time",
+                    s.name(),
+                    conflict
+                );
+            }
+        }
+    }
+
+    pub fn run(&mut self, world: &World, frame: u64) {
+        for s in self.systems.iter_mut() {
+            s.run(world, frame);
         }
     }
 
-    pub fn run(&mut self, world: &World) {
+    pub fn run_measured(&mut self, world: &World, frame: u64) {
         for s in self.systems.iter_mut() {
-            s.run(world);
+            let start = Instant::now();
+            s.run(world, frame);
+            s.run_time = start.elapsed();
         }
     }
 
     pub fn apply(&mut self, world: &mut World) {
         for s in self.systems.iter_mut() {
-            s.apply(world);
+            s.system.apply(world);
         }
     }
 
-    pub fn run_and_apply(&mut self, world: &mut World) {
+    pub fn run_and_apply(&mut self, world: &mut World, frame: u64) {
         for s in self.systems.iter_mut() {
-            s.run(world);
-            s.apply(world);
+            s.run(world, frame);
+            s.system.apply(world);
+        }
+    }
+
+    pub fn has_run_criteria(&self) -> bool {
+        self.run_criteria.is_some()
+    }
+
+    /// Runs and applies the stage until the run criteria says to stop
+    pub fn run_with_criteria(&mut self, world: &mut World, frame: u64, measure: bool) {
+        while let Some(should_run) = self.run_criteria.as_mut().map(|criteria| criteria(world)) {
+            if should_run == ShouldRun::No {
+                break;
+            }
+
+            if measure {
+                self.run_measured(world, frame);
+            } else {
+                self.run(world, frame);
+            }
+            self.apply(world);
+
+            if should_run == ShouldRun::Yes {
+                break;
+            }
         }
     }
 }
@@ -70,7 +169,8 @@ impl SystemsStage {
 /// List of all engine events
 #[derive(Debug, PartialEq, Eq)]
 pub enum EngineEvent {
-    /// Fired when the engine is closing
+    /// Fired when the engine is closing.
+    /// It's handled as an [AppExit::Success]
     Quit,
     /// Only in Android - Fired when the Activity goes in background
     Suspended,
@@ -78,6 +178,76 @@ pub enum EngineEvent {
     Resumed,
 }
 
+/// A [Resource](zengine_ecs::Resource) that contains the number of the current frame
+///
+/// It's `0` during the [Startup Stage](Stage::Startup) and the first frame
+/// and it's incremented by the engine at the end of each frame
+///
+/// # Example
+/// ```
+/// use zengine_ecs::system::Res;
+/// use zengine_engine::FrameCount;
+///
+/// fn log_system(frame_count: Res<FrameCount>) {
+///     println!("frame {}", frame_count.0);
+/// }
+/// ```
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FrameCount(pub u64);
+
+/// An event that requests the engine to stop
+///
+/// The runner stops the engine at the end of the frame in which the event has been published,
+/// runs the [Shutdown Stage](Stage::Shutdown) and returns the exit status
+///
+/// # Example
+/// ```
+/// use zengine_ecs::system::EventPublisher;
+/// use zengine_engine::AppExit;
+///
+/// fn game_over_system(mut exit: EventPublisher<AppExit>) {
+///     exit.publish(AppExit::Success);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppExit {
+    /// The engine stopped without errors
+    Success,
+    /// The engine stopped with the given error code
+    Error(NonZeroU8),
+}
+
+impl AppExit {
+    /// Creates an [AppExit::Error] with the generic error code `1`
+    pub fn error() -> Self {
+        AppExit::Error(NonZeroU8::MIN)
+    }
+
+    /// Creates an exit status from a code, `0` means success
+    pub fn from_code(code: u8) -> Self {
+        NonZeroU8::new(code).map_or(AppExit::Success, AppExit::Error)
+    }
+
+    /// Returns `true` if the engine stopped without errors
+    pub fn is_success(&self) -> bool {
+        matches!(self, AppExit::Success)
+    }
+
+    /// Returns the exit code, `0` means success
+    pub fn code(&self) -> u8 {
+        match self {
+            AppExit::Success => 0,
+            AppExit::Error(code) => code.get(),
+        }
+    }
+}
+
+impl From<AppExit> for ExitCode {
+    fn from(exit: AppExit) -> Self {
+        ExitCode::from(exit.code())
+    }
+}
+
 /// A container of engine logic and data.
 ///
 /// Bundles together the necessary elements to create an engine instance.
@@ -104,12 +274,17 @@ pub enum EngineEvent {
 pub struct Engine {
     stages: HashMap<Stage, SystemsStage>,
     stage_order: Vec<Stage>,
-    running_stages: Vec<SystemsStage>,
+    running_stages: Vec<(Stage, SystemsStage)>,
+    shutdown_stage: Option<SystemsStage>,
     /// The main ECS [`World`] of the [`Engine`].
     /// This stores and provides access to all the data of the application.
     /// The systems of the [`Engine`] will run using this [`World`].
     pub world: World,
-    runner: Box<dyn Fn(Engine)>,
+    pending_modules: Vec<PendingModule>,
+    initialized_modules: Vec<ModuleId>,
+    sub_engines: Vec<SubEngine>,
+    diagnostics_enabled: bool,
+    runner: Box<dyn Fn(Engine) -> AppExit>,
 }
 
 impl Default for Engine {
@@ -118,43 +293,51 @@ impl Default for Engine {
             stages: HashMap::from([
                 (Stage::Startup, SystemsStage::default()),
                 (Stage::PreUpdate, SystemsStage::default()),
+                (Stage::FixedUpdate, SystemsStage::default()),
                 (Stage::Update, SystemsStage::default()),
                 (Stage::PostUpdate, SystemsStage::default()),
                 (Stage::PreRender, SystemsStage::default()),
                 (Stage::Render, SystemsStage::default()),
                 (Stage::PostRender, SystemsStage::default()),
+                (Stage::Shutdown, SystemsStage::default()),
             ]),
             stage_order: vec![
                 Stage::Startup,
                 Stage::PreUpdate,
+                Stage::FixedUpdate,
                 Stage::Update,
                 Stage::PostUpdate,
                 Stage::PreRender,
                 Stage::Render,
                 Stage::PostRender,
+                Stage::Shutdown,
             ],
             running_stages: Vec::default(),
+            shutdown_stage: None,
             world: World::default(),
+            pending_modules: Vec::default(),
+            initialized_modules: Vec::default(),
+            sub_engines: Vec::default(),
+            diagnostics_enabled: false,
             runner: Box::new(default_runner),
         }
     }
 }
 
-fn default_runner(mut engine: Engine) {
+fn default_runner(mut engine: Engine) -> AppExit {
     engine.startup();
 
-    loop {
+    let exit = loop {
         engine.update();
 
-        if engine
-            .world
-            .get_event_handler::<EngineEvent>()
-            .and_then(|event| event.read_last().map(|e| e == &EngineEvent::Quit))
-            .unwrap_or(false)
-        {
-            break;
+        if let Some(exit) = engine.exit_requested() {
+            break exit;
         }
-    }
+    };
+
+    engine.shutdown();
+
+    exit
 }
 
 impl Engine {
@@ -206,25 +389,154 @@ impl Engine {
     }
 
     /// Add a system to the [Engine] pipeling in the specified [Stage]
+    ///
+    /// # Panics
+    /// Panics if the stage is not registered in the engine
     pub fn add_system_into_stage<Params: SystemParam + Any, I: IntoSystem<Params> + Any>(
         &mut self,
         system: I,
         stage: Stage,
     ) -> &mut Self {
-        if let Some(stage) = self.stages.get_mut(&stage) {
-            stage.systems.push(Box::new(system.into_system()));
+        self.get_stage_mut(stage)
+            .systems
+            .push(StageSystem::new(Box::new(system.into_system()), 1));
+
+        self
+    }
+
+    /// Add a system to the [Engine] pipeline in the specified [Stage]
+    /// that runs only one time every `frames` frames
+    ///
+    /// # Example
+    /// ```
+    /// use zengine_engine::{Engine, Stage};
+    ///
+    /// fn autosave_system() {}
+    ///
+    /// Engine::default().add_system_every_n_frames(autosave_system, Stage::PostUpdate, 600);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the stage is not registered in the engine or if `frames` is zero
+    pub fn add_system_every_n_frames<Params: SystemParam + Any, I: IntoSystem<Params> + Any>(
+        &mut self,
+        system: I,
+        stage: Stage,
+        frames: u64,
+    ) -> &mut Self {
+        if frames == 0 {
+            panic!("The frame interval of a system should be greater than zero");
+        }
+
+        self.get_stage_mut(stage)
+            .systems
+            .push(StageSystem::new(Box::new(system.into_system()), frames));
+
+        self
+    }
+
+    /// Add a new [Stage] to the pipeline that runs just before the target stage
+    ///
+    /// # Example
+    /// ```
+    /// use zengine_engine::{Engine, Stage};
+    ///
+    /// fn receive_packets() {}
+    ///
+    /// Engine::default()
+    ///     .add_stage_before(Stage::Custom("NetworkReceive"), Stage::PreUpdate)
+    ///     .add_system_into_stage(receive_packets, Stage::Custom("NetworkReceive"));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the new stage already exists, if the target stage is not registered
+    /// or if the target stage is the [Startup Stage](Stage::Startup)
+    pub fn add_stage_before(&mut self, stage: Stage, target: Stage) -> &mut Self {
+        if target == Stage::Startup {
+            panic!("Cannot add the stage {:?} before the Startup stage", stage);
         }
+        let index = self.get_stage_index(stage, target);
+        self.insert_stage(stage, index);
 
         self
     }
 
-    /// Add a [Module] to the engine
-    pub fn add_module(&mut self, module: impl Module) -> &mut Self {
-        module.init(self);
+    /// Add a new [Stage] to the pipeline that runs just after the target stage
+    ///
+    /// # Panics
+    /// Panics if the new stage already exists, if the target stage is not registered
+    /// or if the target stage is the [Shutdown Stage](Stage::Shutdown)
+    pub fn add_stage_after(&mut self, stage: Stage, target: Stage) -> &mut Self {
+        if target == Stage::Shutdown {
+            panic!("Cannot add the stage {:?} after the Shutdown stage", stage);
+        }
+        let index = self.get_stage_index(stage, target);
+        self.insert_stage(stage, index + 1);
 
         self
     }
 
+    fn get_stage_index(&self, stage: Stage, target: Stage) -> usize {
+        if self.stages.contains_key(&stage) {
+            panic!("Stage {:?} already exists", stage);
+        }
+
+        self.stage_order
+            .iter()
+            .position(|s| *s == target)
+            .unwrap_or_else(|| panic!("Stage {:?} not found", target))
+    }
+
+    fn insert_stage(&mut self, stage: Stage, index: usize) {
+        self.stage_order.insert(index, stage);
+        self.stages.insert(stage, SystemsStage::default());
+    }
+
+    fn get_stage_mut(&mut self, stage: Stage) -> &mut SystemsStage {
+        self.stages
+            .get_mut(&stage)
+            .unwrap_or_else(|| panic!("Stage {:?} not found", stage))
+    }
+
+    /// Set the run criteria of a [Stage]
+    ///
+    /// The run criteria is evaluated each frame before running the stage.
+    /// A stage with a run criteria is applied immediately after each run
+    /// so a criteria that returns [ShouldRun::YesAndCheckAgain] can run the stage
+    /// multiple times in the same frame
+    pub fn set_stage_run_criteria<F: FnMut(&mut World) -> ShouldRun + 'static>(
+        &mut self,
+        stage: Stage,
+        run_criteria: F,
+    ) -> &mut Self {
+        self.get_stage_mut(stage).run_criteria = Some(Box::new(run_criteria));
+
+        self
+    }
+
+    /// Add a [SubEngine] to the engine
+    ///
+    /// The sub engine starts together with the main engine and
+    /// it's updated after each main engine update, right after its extract step
+    pub fn add_sub_engine(&mut self, sub_engine: SubEngine) -> &mut Self {
+        if self.get_sub_engine(sub_engine.label()).is_some() {
+            panic!("SubEngine with label {} already exists", sub_engine.label());
+        }
+        self.sub_engines.push(sub_engine);
+
+        self
+    }
+
+    /// Returns a reference to the [SubEngine] with the given label
+    pub fn get_sub_engine(&self, label: &str) -> Option<&SubEngine> {
+        self.sub_engines.iter().find(|s| s.label() == label)
+    }
+
+    /// Returns a mutable reference to the [SubEngine] with the given label
+    pub fn get_sub_engine_mut(&mut self, label: &str) -> Option<&mut SubEngine> {
+        self.sub_engines.iter_mut().find(|s| s.label() == label)
+    }
+
     /// Set the engine runner funtion
     ///
     /// This function is responsable of running the main event loop of the engine.
@@ -232,66 +544,303 @@ impl Engine {
     /// By default the engine use this runner implementation
     ///
     /// ```
-    /// use zengine_engine::{Engine, EngineEvent};
+    /// use zengine_engine::{AppExit, Engine};
     ///
-    /// fn default_runner(mut engine: Engine) {
+    /// fn default_runner(mut engine: Engine) -> AppExit {
     ///     engine.startup();
     ///
-    ///     loop {
+    ///     let exit = loop {
     ///         engine.update();
     ///
-    ///         if engine
-    ///             .world
-    ///             .get_event_handler::<EngineEvent>()
-    ///             .and_then(|event| event.read_last().map(|e| e == &EngineEvent::Quit))
-    ///             .unwrap_or(false)
-    ///         {
-    ///             break;
+    ///         if let Some(exit) = engine.exit_requested() {
+    ///             break exit;
     ///         }
-    ///     }
+    ///     };
+    ///
+    ///     engine.shutdown();
+    ///
+    ///     exit
     /// }
     ///```
-    pub fn set_runner<F: Fn(Engine) + 'static>(&mut self, runner: F) -> &mut Self {
+    pub fn set_runner<F: Fn(Engine) -> AppExit + 'static>(&mut self, runner: F) -> &mut Self {
         self.runner = Box::new(runner);
         self
     }
 
     /// Startup function of the engine. Should be called only one time before the update function
+    ///
+    /// Initializes the added modules and runs the [Startup Stage](Stage::Startup)
     pub fn startup(&mut self) {
-        let mut stages: Vec<SystemsStage> = self
+        self.init_modules();
+
+        let mut stages: Vec<(Stage, SystemsStage)> = self
             .stage_order
             .iter()
-            .map(|stage| self.stages.remove(stage).unwrap())
+            .map(|stage| (*stage, self.stages.remove(stage).unwrap()))
             .collect();
 
-        for stage in stages.iter_mut() {
+        if self.world.get_resource::<FrameCount>().is_none() {
+            self.world.create_resource(FrameCount::default());
+        }
+
+        for (_, stage) in stages.iter_mut() {
             stage.init(&mut self.world);
         }
 
-        let mut startup_stage = stages.remove(0);
-        startup_stage.run_and_apply(&mut self.world);
+        let startup_index = stages
+            .iter()
+            .position(|(stage, _)| *stage == Stage::Startup)
+            .expect("startup stage should be present");
+        let (_, mut startup_stage) = stages.remove(startup_index);
+        let frame = self.frame();
+        startup_stage.run_and_apply(&mut self.world, frame);
+
+        let shutdown_index = stages
+            .iter()
+            .position(|(stage, _)| *stage == Stage::Shutdown)
+            .expect("shutdown stage should be present");
+        self.shutdown_stage = Some(stages.remove(shutdown_index).1);
 
         self.running_stages = stages;
+
+        for sub_engine in self.sub_engines.iter_mut() {
+            sub_engine.startup();
+        }
     }
 
     /// Update function of the engine. Should be called only one time for each frame
     pub fn update(&mut self) {
-        for stage in self.running_stages.iter_mut() {
-            stage.run(&self.world);
+        let frame = self.frame();
+
+        for (_, stage) in self.running_stages.iter_mut() {
+            if stage.has_run_criteria() {
+                stage.run_with_criteria(&mut self.world, frame, self.diagnostics_enabled);
+            } else if self.diagnostics_enabled {
+                stage.run_measured(&self.world, frame);
+            } else {
+                stage.run(&self.world, frame);
+            }
+        }
+
+        for (_, stage) in self.running_stages.iter_mut() {
+            if !stage.has_run_criteria() {
+                stage.apply(&mut self.world);
+            }
+        }
+
+        if self.diagnostics_enabled {
+            self.update_diagnostics(frame);
+        }
+
+        if let Some(mut frame_count) = self.world.get_mut_resource::<FrameCount>() {
+            frame_count.0 = frame_count.0.wrapping_add(1);
         }
 
-        for stage in self.running_stages.iter_mut() {
-            stage.apply(&mut self.world);
+        for sub_engine in self.sub_engines.iter_mut() {
+            sub_engine.extract(&mut self.world);
+            sub_engine.update();
+        }
+    }
+
+    fn frame(&self) -> u64 {
+        self.world
+            .get_resource::<FrameCount>()
+            .map_or(0, |frame_count| frame_count.0)
+    }
+
+    /// Shutdown function of the engine. Should be called only one time by the runner
+    /// after the last update
+    ///
+    /// Runs the [Shutdown Stage](Stage::Shutdown) of the engine and of its sub engines
+    pub fn shutdown(&mut self) {
+        if let Some(mut shutdown_stage) = self.shutdown_stage.take() {
+            let frame = self.frame();
+            shutdown_stage.run_and_apply(&mut self.world, frame);
+        }
+
+        for sub_engine in self.sub_engines.iter_mut() {
+            sub_engine.shutdown();
+        }
+    }
+
+    /// Returns the exit status if an [AppExit] or an [EngineEvent::Quit]
+    /// has been published during the last frame
+    pub fn exit_requested(&self) -> Option<AppExit> {
+        let exit = self
+            .world
+            .get_event_handler::<AppExit>()
+            .and_then(|event| event.read_last().copied());
+
+        exit.or_else(|| {
+            self.world
+                .get_event_handler::<EngineEvent>()
+                .and_then(|event| event.read_last().map(|e| e == &EngineEvent::Quit))
+                .unwrap_or(false)
+                .then_some(AppExit::Success)
+        })
+    }
+
+    pub(crate) fn create_engine_event_handlers(&mut self) {
+        if self.world.get_event_handler::<EngineEvent>().is_none() {
+            self.world.create_event_handler::<EngineEvent>();
+        }
+        if self.world.get_event_handler::<AppExit>().is_none() {
+            self.world.create_event_handler::<AppExit>();
         }
     }
 
     /// Starts the engine by calling the engine's runner function
-    pub fn run(&mut self) {
-        self.world.create_event_handler::<EngineEvent>();
+    /// and returns the exit status of the engine
+    pub fn run(&mut self) -> AppExit {
+        self.create_engine_event_handlers();
+        self.init_modules();
 
         let mut app = std::mem::take(self);
         let runner = std::mem::replace(&mut app.runner, Box::new(default_runner));
 
-        (runner)(app);
+        (runner)(app)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zengine_ecs::system::{EventPublisher, ResMut};
+    use zengine_macro::Resource;
+
+    use crate::{AppExit, Engine, FrameCount, ShouldRun, Stage};
+
+    #[derive(Resource, Default, Debug)]
+    struct Counter(u32);
+
+    fn count_system(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    #[test]
+    fn stage_run_criteria() {
+        let mut engine = Engine::default();
+
+        let mut runs = 0;
+        engine
+            .add_system_into_stage(count_system, Stage::FixedUpdate)
+            .set_stage_run_criteria(Stage::FixedUpdate, move |_| {
+                runs += 1;
+                if runs % 4 == 0 {
+                    ShouldRun::No
+                } else {
+                    ShouldRun::YesAndCheckAgain
+                }
+            });
+
+        engine.startup();
+        engine.update();
+        assert_eq!(engine.world.get_resource::<Counter>().unwrap().0, 3);
+
+        engine.update();
+        assert_eq!(engine.world.get_resource::<Counter>().unwrap().0, 6);
+    }
+
+    #[test]
+    fn stage_skipped_by_run_criteria() {
+        let mut engine = Engine::default();
+        engine
+            .add_system_into_stage(count_system, Stage::FixedUpdate)
+            .set_stage_run_criteria(Stage::FixedUpdate, |_| ShouldRun::No);
+
+        engine.startup();
+        engine.update();
+
+        assert_eq!(engine.world.get_resource::<Counter>().unwrap().0, 0);
+    }
+
+    #[test]
+    fn custom_stages_order() {
+        let mut engine = Engine::default();
+        engine
+            .add_stage_before(Stage::Custom("First"), Stage::PreUpdate)
+            .add_stage_after(Stage::Custom("Last"), Stage::PostRender)
+            .add_stage_after(Stage::Custom("Middle"), Stage::Update);
+
+        assert_eq!(
+            engine.stage_order,
+            vec![
+                Stage::Startup,
+                Stage::Custom("First"),
+                Stage::PreUpdate,
+                Stage::FixedUpdate,
+                Stage::Update,
+                Stage::Custom("Middle"),
+                Stage::PostUpdate,
+                Stage::PreRender,
+                Stage::Render,
+                Stage::PostRender,
+                Stage::Custom("Last"),
+                Stage::Shutdown,
+            ]
+        );
+
+        engine.add_system_into_stage(count_system, Stage::Custom("Middle"));
+        engine.startup();
+        engine.update();
+
+        assert_eq!(engine.world.get_resource::<Counter>().unwrap().0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Stage Custom(\"Missing\") not found")]
+    fn add_system_into_missing_stage() {
+        Engine::default().add_system_into_stage(count_system, Stage::Custom("Missing"));
+    }
+
+    #[test]
+    #[should_panic(expected = "already exists")]
+    fn add_duplicated_stage() {
+        Engine::default()
+            .add_stage_after(Stage::Custom("Net"), Stage::PreUpdate)
+            .add_stage_after(Stage::Custom("Net"), Stage::Update);
+    }
+
+    #[test]
+    fn run_returns_exit_status() {
+        fn exit_system(mut exit: EventPublisher<AppExit>) {
+            exit.publish(AppExit::from_code(3));
+        }
+
+        let mut engine = Engine::default();
+        engine.add_system(exit_system);
+
+        let exit = engine.run();
+        assert_eq!(exit.code(), 3);
+        assert!(!exit.is_success());
+    }
+
+    #[test]
+    fn shutdown_stage_runs_once() {
+        let mut engine = Engine::default();
+        engine.add_system_into_stage(count_system, Stage::Shutdown);
+
+        engine.startup();
+        engine.update();
+        engine.update();
+        assert_eq!(engine.world.get_resource::<Counter>().unwrap().0, 0);
+
+        engine.shutdown();
+        engine.shutdown();
+        assert_eq!(engine.world.get_resource::<Counter>().unwrap().0, 1);
+    }
+
+    #[test]
+    fn run_system_every_n_frames() {
+        let mut engine = Engine::default();
+        engine.add_system_every_n_frames(count_system, Stage::Update, 3);
+
+        engine.startup();
+        for _ in 0..7 {
+            engine.update();
+        }
+
+        assert_eq!(engine.world.get_resource::<FrameCount>().unwrap().0, 7);
+        // runs on frames 0, 3 and 6
+        assert_eq!(engine.world.get_resource::<Counter>().unwrap().0, 3);
     }
 }
@@ -0,0 +1,332 @@
+use std::any::TypeId;
+
+use crate::Engine;
+
+/// A collection of engine logics and configurations.
+///
+/// A Module configure the [`Engine`]. When the [`Engine`] starts,
+/// the module's [`Module::init`] function is call.
+///
+/// The modules are initialized after the modules they depend on,
+/// otherwise they keep the order in which they were added.
+pub trait Module: 'static {
+    /// Configures the [`Engine`] to which this module is added.
+    fn init(self, engine: &mut Engine);
+
+    /// Returns the modules that must be initialized before this one
+    fn dependencies(&self) -> Vec<ModuleId> {
+        Vec::default()
+    }
+
+    /// Returns `true` if the module can be added only one time to the [`Engine`]
+    fn is_unique(&self) -> bool {
+        true
+    }
+}
+
+/// Identifies a [Module] type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModuleId {
+    type_id: TypeId,
+    name: &'static str,
+}
+
+impl ModuleId {
+    /// Returns the identifier of the given [Module] type
+    pub fn of<M: Module>() -> Self {
+        Self {
+            type_id: TypeId::of::<M>(),
+            name: std::any::type_name::<M>(),
+        }
+    }
+
+    /// Returns the type name of the module
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+pub(crate) struct PendingModule {
+    id: ModuleId,
+    unique: bool,
+    dependencies: Vec<ModuleId>,
+    init: Box<dyn FnOnce(&mut Engine)>,
+}
+
+impl PendingModule {
+    fn new<M: Module>(module: M) -> Self {
+        Self {
+            id: ModuleId::of::<M>(),
+            unique: module.is_unique(),
+            dependencies: module.dependencies(),
+            init: Box::new(move |engine| module.init(engine)),
+        }
+    }
+}
+
+/// A group of [Module]s that are added together to the [Engine]
+///
+/// # Example
+/// ```
+/// use zengine_engine::{Engine, Module, ModuleGroup, ModuleGroupBuilder};
+///
+/// struct NetworkModule;
+/// impl Module for NetworkModule {
+///     fn init(self, _engine: &mut Engine) {}
+/// }
+///
+/// struct AiModule;
+/// impl Module for AiModule {
+///     fn init(self, _engine: &mut Engine) {}
+/// }
+///
+/// struct ServerModules;
+/// impl ModuleGroup for ServerModules {
+///     fn build(self) -> ModuleGroupBuilder {
+///         ModuleGroupBuilder::default()
+///             .add_module(NetworkModule)
+///             .add_module(AiModule)
+///     }
+/// }
+///
+/// Engine::default().add_modules(ServerModules.build().disable::<AiModule>());
+/// ```
+pub trait ModuleGroup {
+    /// Returns the builder that contains the modules of the group
+    fn build(self) -> ModuleGroupBuilder;
+}
+
+/// Contains the [Module]s of a [ModuleGroup]
+///
+/// It allows to disable or replace a single module of the group
+/// before adding it to the [Engine]
+#[derive(Default)]
+pub struct ModuleGroupBuilder {
+    modules: Vec<PendingModule>,
+}
+
+impl ModuleGroupBuilder {
+    /// Adds a module at the end of the group
+    pub fn add_module<M: Module>(mut self, module: M) -> Self {
+        self.modules.push(PendingModule::new(module));
+        self
+    }
+
+    /// Replaces the module of the same type already present in the group
+    ///
+    /// # Panics
+    /// Panics if the group doesn't contain a module of the same type
+    pub fn set<M: Module>(mut self, module: M) -> Self {
+        let index = self.index_of::<M>();
+        self.modules[index] = PendingModule::new(module);
+        self
+    }
+
+    /// Removes the module of the given type from the group
+    ///
+    /// # Panics
+    /// Panics if the group doesn't contain a module of the given type
+    pub fn disable<M: Module>(mut self) -> Self {
+        let index = self.index_of::<M>();
+        self.modules.remove(index);
+        self
+    }
+
+    fn index_of<M: Module>(&self) -> usize {
+        let id = ModuleId::of::<M>();
+        self.modules
+            .iter()
+            .position(|module| module.id == id)
+            .unwrap_or_else(|| panic!("Module {} is not part of the group", id.name()))
+    }
+}
+
+impl ModuleGroup for ModuleGroupBuilder {
+    fn build(self) -> ModuleGroupBuilder {
+        self
+    }
+}
+
+impl Engine {
+    /// Add a [Module] to the engine
+    ///
+    /// The module is initialized when the engine starts
+    ///
+    /// # Panics
+    /// Panics if the module is unique and it has already been added
+    pub fn add_module<M: Module>(&mut self, module: M) -> &mut Self {
+        self.add_pending_module(PendingModule::new(module));
+
+        self
+    }
+
+    /// Add all the [Module]s of a [ModuleGroup] to the engine
+    pub fn add_modules<G: ModuleGroup>(&mut self, group: G) -> &mut Self {
+        for module in group.build().modules {
+            self.add_pending_module(module);
+        }
+
+        self
+    }
+
+    fn add_pending_module(&mut self, module: PendingModule) {
+        if module.unique
+            && (self.initialized_modules.contains(&module.id)
+                || self.pending_modules.iter().any(|m| m.id == module.id))
+        {
+            panic!("Module {} has already been added", module.id.name());
+        }
+
+        self.pending_modules.push(module);
+    }
+
+    pub(crate) fn init_modules(&mut self) {
+        while !self.pending_modules.is_empty() {
+            let pending = std::mem::take(&mut self.pending_modules);
+            for module in sort_modules(pending, &self.initialized_modules) {
+                self.initialized_modules.push(module.id);
+                (module.init)(self);
+            }
+        }
+    }
+}
+
+fn sort_modules(mut pending: Vec<PendingModule>, initialized: &[ModuleId]) -> Vec<PendingModule> {
+    for module in pending.iter() {
+        if let Some(missing) = module.dependencies.iter().find(|dependency| {
+            !initialized.contains(dependency) && !pending.iter().any(|m| m.id == **dependency)
+        }) {
+            panic!(
+                "Module {} depends on {} that has not been added",
+                module.id.name(),
+                missing.name()
+            );
+        }
+    }
+
+    let mut sorted: Vec<PendingModule> = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        let ready = pending.iter().position(|module| {
+            module.dependencies.iter().all(|dependency| {
+                initialized.contains(dependency) || sorted.iter().any(|m| m.id == *dependency)
+            })
+        });
+
+        match ready {
+            Some(index) => sorted.push(pending.remove(index)),
+            None => panic!(
+                "Circular dependency between the modules: {}",
+                pending
+                    .iter()
+                    .map(|m| m.id.name())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+
+    sorted
+}
+
+#[cfg(test)]
+mod tests {
+    use zengine_macro::Resource;
+
+    use crate::{Engine, Module, ModuleGroup, ModuleGroupBuilder, ModuleId};
+
+    #[derive(Resource, Debug, Default)]
+    struct InitOrder(Vec<&'static str>);
+
+    fn push_init(engine: &mut Engine, name: &'static str) {
+        if engine.world.get_resource::<InitOrder>().is_none() {
+            engine.world.create_resource(InitOrder::default());
+        }
+        engine
+            .world
+            .get_mut_resource::<InitOrder>()
+            .unwrap()
+            .0
+            .push(name);
+    }
+
+    struct ModuleA;
+    impl Module for ModuleA {
+        fn init(self, engine: &mut Engine) {
+            push_init(engine, "A");
+        }
+    }
+
+    struct ModuleB;
+    impl Module for ModuleB {
+        fn init(self, engine: &mut Engine) {
+            push_init(engine, "B");
+        }
+
+        fn dependencies(&self) -> Vec<ModuleId> {
+            vec![ModuleId::of::<ModuleA>()]
+        }
+    }
+
+    struct ModuleC(&'static str);
+    impl Module for ModuleC {
+        fn init(self, engine: &mut Engine) {
+            push_init(engine, self.0);
+        }
+
+        fn dependencies(&self) -> Vec<ModuleId> {
+            vec![ModuleId::of::<ModuleB>()]
+        }
+    }
+
+    struct Group;
+    impl ModuleGroup for Group {
+        fn build(self) -> ModuleGroupBuilder {
+            ModuleGroupBuilder::default()
+                .add_module(ModuleA)
+                .add_module(ModuleB)
+                .add_module(ModuleC("C"))
+        }
+    }
+
+    fn init_order(engine: &Engine) -> Vec<&'static str> {
+        engine.world.get_resource::<InitOrder>().unwrap().0.clone()
+    }
+
+    #[test]
+    fn init_after_dependencies() {
+        let mut engine = Engine::default();
+        engine
+            .add_module(ModuleC("C"))
+            .add_module(ModuleB)
+            .add_module(ModuleA);
+
+        assert!(engine.world.get_resource::<InitOrder>().is_none());
+
+        engine.startup();
+
+        assert_eq!(init_order(&engine), vec!["A", "B", "C"]);
+    }
+
+    #[test]
+    fn replace_module_of_group() {
+        let mut engine = Engine::default();
+        engine.add_modules(Group.build().set(ModuleC("Replaced")));
+        engine.startup();
+
+        assert_eq!(init_order(&engine), vec!["A", "B", "Replaced"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "depends on")]
+    fn missing_dependency() {
+        let mut engine = Engine::default();
+        engine.add_modules(Group.build().disable::<ModuleA>());
+        engine.startup();
+    }
+
+    #[test]
+    #[should_panic(expected = "has already been added")]
+    fn duplicated_module() {
+        Engine::default().add_module(ModuleA).add_module(ModuleA);
+    }
+}
@@ -0,0 +1,310 @@
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+};
+
+use zengine_ecs::{
+    query::QueryIter,
+    system::{IntoSystem, SystemParam},
+    Component, Entity, Resource, World,
+};
+
+use crate::{Engine, Module, Stage};
+
+type Extractor = Box<dyn FnMut(&mut World, &mut World, &mut EntityMap)>;
+
+/// Maps the entities of the main [World] to the entities of a [SubEngine] world
+///
+/// The extract step uses this map to keep track of which entity of the sub world
+/// mirrors an entity of the main world.
+#[derive(Default, Debug)]
+pub struct EntityMap {
+    map: HashMap<Entity, Entity>,
+}
+
+impl EntityMap {
+    /// Returns the sub world entity that mirrors the given main world entity
+    pub fn get(&self, main_entity: Entity) -> Option<Entity> {
+        self.map.get(&main_entity).copied()
+    }
+
+    /// Returns the sub world entity that mirrors the given main world entity
+    /// spawning a new one in the sub world if it doesn't exist yet
+    pub fn get_or_spawn(&mut self, main_entity: Entity, sub_world: &mut World) -> Entity {
+        *self
+            .map
+            .entry(main_entity)
+            .or_insert_with(|| sub_world.spawn(()))
+    }
+
+    /// Returns an iterator over the `(main entity, sub entity)` pairs
+    pub fn iter(&self) -> impl Iterator<Item = (&Entity, &Entity)> {
+        self.map.iter()
+    }
+
+    fn despawn_orphans(&mut self, main_world: &World, sub_world: &mut World) {
+        self.map.retain(|main_entity, sub_entity| {
+            if main_world.contains(*main_entity) {
+                true
+            } else {
+                sub_world.despawn(*sub_entity);
+                false
+            }
+        });
+    }
+}
+
+/// A secondary engine pipeline that owns a separate [World]
+///
+/// A SubEngine runs alongside the main [Engine]. Each frame, after the main
+/// engine update, the extract step copies the selected data from the main world
+/// into the sub world and then the sub engine pipeline is updated.
+///
+/// # Example
+/// ```
+/// use zengine_engine::{Engine, SubEngine};
+/// use zengine_ecs::query::{Query, QueryIter};
+/// use zengine_macro::Component;
+///
+/// #[derive(Component, Clone, Debug)]
+/// struct Position(f32);
+///
+/// fn render_system(query: Query<(&Position,)>) {
+///     for position in query.iter() {
+///         println!("{:?}", position);
+///     }
+/// }
+///
+/// let mut render = SubEngine::new("render");
+/// render
+///     .extract_component::<Position>()
+///     .add_system(render_system);
+///
+/// Engine::default().add_sub_engine(render);
+/// ```
+pub struct SubEngine {
+    label: &'static str,
+    engine: Engine,
+    extractors: Vec<Extractor>,
+    entity_map: EntityMap,
+}
+
+impl SubEngine {
+    /// Creates a new SubEngine identified by the given label
+    pub fn new(label: &'static str) -> Self {
+        Self {
+            label,
+            engine: Engine::default(),
+            extractors: Vec::default(),
+            entity_map: EntityMap::default(),
+        }
+    }
+
+    /// Returns the label of the SubEngine
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// Returns a reference to the SubEngine [World]
+    pub fn world(&self) -> &World {
+        &self.engine.world
+    }
+
+    /// Returns a mutable reference to the SubEngine [World]
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.engine.world
+    }
+
+    /// Returns the [EntityMap] used by the extract step
+    pub fn entity_map(&self) -> &EntityMap {
+        &self.entity_map
+    }
+
+    /// Add a system to the SubEngine [Update Stage](Stage::Update)
+    pub fn add_system<Params: SystemParam + Any, I: IntoSystem<Params> + Any>(
+        &mut self,
+        system: I,
+    ) -> &mut Self {
+        self.engine.add_system(system);
+        self
+    }
+
+    /// Add a system to the SubEngine [Startup Stage](Stage::Startup)
+    pub fn add_startup_system<Params: SystemParam + Any, I: IntoSystem<Params> + Any>(
+        &mut self,
+        system: I,
+    ) -> &mut Self {
+        self.engine.add_startup_system(system);
+        self
+    }
+
+    /// Add a system to the SubEngine pipeline in the specified [Stage]
+    pub fn add_system_into_stage<Params: SystemParam + Any, I: IntoSystem<Params> + Any>(
+        &mut self,
+        system: I,
+        stage: Stage,
+    ) -> &mut Self {
+        self.engine.add_system_into_stage(system, stage);
+        self
+    }
+
+    /// Add a new [Stage] to the SubEngine pipeline just before the target stage
+    pub fn add_stage_before(&mut self, stage: Stage, target: Stage) -> &mut Self {
+        self.engine.add_stage_before(stage, target);
+        self
+    }
+
+    /// Add a new [Stage] to the SubEngine pipeline just after the target stage
+    pub fn add_stage_after(&mut self, stage: Stage, target: Stage) -> &mut Self {
+        self.engine.add_stage_after(stage, target);
+        self
+    }
+
+    /// Add a [Module] to the SubEngine
+    pub fn add_module(&mut self, module: impl Module) -> &mut Self {
+        self.engine.add_module(module);
+        self
+    }
+
+    /// Add a custom extract function
+    ///
+    /// The function receives the main world, the sub world and the [EntityMap]
+    /// and runs each frame before the SubEngine update
+    pub fn add_extract<F: FnMut(&mut World, &mut World, &mut EntityMap) + 'static>(
+        &mut self,
+        extract: F,
+    ) -> &mut Self {
+        self.extractors.push(Box::new(extract));
+        self
+    }
+
+    /// Copies a resource from the main world into the sub world each frame
+    pub fn extract_resource<R: Resource + Clone + 'static>(&mut self) -> &mut Self {
+        self.add_extract(|main_world, sub_world, _| {
+            let resource = main_world.get_resource::<R>().map(|r| r.clone());
+            if let Some(resource) = resource {
+                sub_world.create_resource(resource);
+            }
+        })
+    }
+
+    /// Copies a component from the main world entities into the mirrored
+    /// sub world entities each frame
+    ///
+    /// When the component is removed from a main world entity
+    /// it's also removed from the mirrored one
+    pub fn extract_component<C: Component + Clone>(&mut self) -> &mut Self {
+        let mut extracted = HashSet::<Entity>::default();
+        self.add_extract(move |main_world, sub_world, entity_map| {
+            let components: Vec<(Entity, C)> = {
+                let mut query = main_world.query::<(Entity, &C)>();
+                let query = query.run(main_world);
+                query.iter().map(|(e, c)| (*e, c.clone())).collect()
+            };
+
+            let mut current = HashSet::with_capacity(components.len());
+            for (main_entity, component) in components {
+                let sub_entity = entity_map.get_or_spawn(main_entity, sub_world);
+                sub_world.add_component(sub_entity, component);
+                current.insert(sub_entity);
+            }
+
+            for sub_entity in extracted.difference(&current) {
+                if sub_world.contains(*sub_entity) {
+                    sub_world.remove_component::<C>(*sub_entity);
+                }
+            }
+
+            extracted = current;
+        })
+    }
+
+    pub(crate) fn startup(&mut self) {
+        self.engine.startup();
+    }
+
+    pub(crate) fn shutdown(&mut self) {
+        self.engine.shutdown();
+    }
+
+    pub(crate) fn extract(&mut self, main_world: &mut World) {
+        for extract in self.extractors.iter_mut() {
+            extract(main_world, &mut self.engine.world, &mut self.entity_map);
+        }
+
+        self.entity_map
+            .despawn_orphans(main_world, &mut self.engine.world);
+    }
+
+    pub(crate) fn update(&mut self) {
+        self.engine.update();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zengine_ecs::{
+        query::{Query, QueryIter, QueryIterMut},
+        system::ResMut,
+    };
+    use zengine_macro::{Component, Resource};
+
+    use crate::{Engine, SubEngine};
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Position(u32);
+
+    #[derive(Resource, Default, Clone, Debug)]
+    struct Counter(u32);
+
+    fn move_system(mut query: Query<(&mut Position,)>) {
+        for position in query.iter_mut() {
+            position.0 += 1;
+        }
+    }
+
+    fn count_system(query: Query<(&Position,)>, mut counter: ResMut<Counter>) {
+        counter.0 = query.iter().map(|p| p.0).sum();
+    }
+
+    #[test]
+    fn extract_component_into_sub_world() {
+        let mut engine = Engine::default();
+        engine.add_system(move_system);
+
+        let mut sub = SubEngine::new("test");
+        sub.extract_component::<Position>().add_system(count_system);
+        engine.add_sub_engine(sub);
+
+        let entity = engine.world.spawn(Position(0));
+        engine.world.spawn(Position(10));
+
+        engine.startup();
+        engine.update();
+
+        let sub = engine.get_sub_engine("test").unwrap();
+        assert_eq!(sub.world().get_resource::<Counter>().unwrap().0, 12);
+
+        engine.world.despawn(entity);
+        engine.update();
+
+        let sub = engine.get_sub_engine("test").unwrap();
+        assert_eq!(sub.entity_map().iter().count(), 1);
+    }
+
+    #[test]
+    fn extract_resource_into_sub_world() {
+        let mut engine = Engine::default();
+        engine.world.create_resource(Counter(5));
+
+        let mut sub = SubEngine::new("test");
+        sub.extract_resource::<Counter>();
+        engine.add_sub_engine(sub);
+
+        engine.startup();
+        engine.update();
+
+        let sub = engine.get_sub_engine("test").unwrap();
+        assert_eq!(sub.world().get_resource::<Counter>().unwrap().0, 5);
+    }
+}
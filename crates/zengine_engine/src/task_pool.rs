@@ -0,0 +1,414 @@
+use std::{
+    future::Future,
+    sync::{
+        atomic::{self, AtomicBool},
+        Arc,
+    },
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    cmp::Ordering,
+    collections::BinaryHeap,
+    pin::Pin,
+    sync::{Condvar, Mutex},
+};
+
+use zengine_macro::Resource;
+
+/// The priority of a task spawned in a [TaskPool]
+///
+/// Queued tasks with an higher priority run first,
+/// tasks with the same priority run in the order they were spawned
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaskPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+#[derive(Debug, Default)]
+struct TaskState {
+    canceled: AtomicBool,
+    finished: AtomicBool,
+}
+
+/// A handle to a task spawned in a [TaskPool]
+///
+/// Dropping the handle doesn't cancel the task
+#[derive(Debug, Clone, Default)]
+pub struct Task {
+    state: Arc<TaskState>,
+}
+
+impl Task {
+    /// Cancels the task
+    ///
+    /// A queued task will never run, a running task can stop early
+    /// checking [Task::is_canceled]. See [TaskPool::spawn_cancelable]
+    pub fn cancel(&self) {
+        self.state.canceled.store(true, atomic::Ordering::Release);
+    }
+
+    /// Returns `true` if the task has been canceled
+    pub fn is_canceled(&self) -> bool {
+        self.state.canceled.load(atomic::Ordering::Acquire)
+    }
+
+    /// Returns `true` if the task has run or has been discarded after a cancel
+    pub fn is_finished(&self) -> bool {
+        self.state.finished.load(atomic::Ordering::Acquire)
+    }
+
+    fn finish(&self) {
+        self.state.finished.store(true, atomic::Ordering::Release);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct QueuedTask {
+    priority: TaskPriority,
+    sequence: u64,
+    task: Task,
+    future: Pin<Box<dyn Future<Output = ()> + Send>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PartialEq for QueuedTask {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Eq for QueuedTask {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PartialOrd for QueuedTask {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Ord for QueuedTask {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // the oldest task comes first between tasks with the same priority
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Default)]
+struct Queue {
+    tasks: BinaryHeap<QueuedTask>,
+    sequence: u64,
+    shutdown: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct Workers {
+    threads: usize,
+    started: Mutex<bool>,
+    queue: Arc<(Mutex<Queue>, Condvar)>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Workers {
+    fn start(&self) {
+        let mut started = self.started.lock().unwrap();
+        if *started {
+            return;
+        }
+        *started = true;
+
+        for index in 0..self.threads {
+            let queue = self.queue.clone();
+            std::thread::Builder::new()
+                .name(format!("zengine-task-{}", index))
+                .spawn(move || run_worker(&queue))
+                .expect("Failed to spawn a task pool thread");
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for Workers {
+    fn drop(&mut self) {
+        let (queue, available) = &*self.queue;
+        queue.lock().unwrap().shutdown = true;
+        available.notify_all();
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn run_worker(queue: &(Mutex<Queue>, Condvar)) {
+    let (queue, available) = queue;
+    loop {
+        let queued = {
+            let mut queue = queue.lock().unwrap();
+            loop {
+                // the tasks spawned before the pool is dropped still run
+                if let Some(queued) = queue.tasks.pop() {
+                    break queued;
+                }
+                if queue.shutdown {
+                    return;
+                }
+                queue = available.wait(queue).unwrap();
+            }
+        };
+
+        if !queued.task.is_canceled() {
+            let future = queued.future;
+            // a panicking task must not kill the worker thread
+            if std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| pollster::block_on(future)))
+                .is_err()
+            {
+                log::error!("A task of the task pool panicked");
+            }
+        }
+        queued.task.finish();
+    }
+}
+
+/// A [Resource](zengine_ecs::Resource) that runs async tasks on a bounded number of threads
+///
+/// The threads are started when the first task is spawned.
+/// Cloning the pool shares the same threads, they stop when all the clones are dropped
+/// after running the tasks already spawned.
+///
+/// The engine modules use the `TaskPool` resource when present,
+/// so the number of threads can be configured creating the resource
+/// before running the engine.
+///
+/// On the web the tasks run on the browser event loop
+/// and the number of threads and the priorities are ignored.
+///
+/// # Example
+/// ```
+/// use zengine_engine::{Engine, TaskPool, TaskPriority};
+///
+/// let mut engine = Engine::default();
+/// engine.world.create_resource(TaskPool::new(2));
+///
+/// let task_pool = TaskPool::new(1);
+/// let task = task_pool.spawn(TaskPriority::High, async {
+///     println!("Hello from the task pool");
+/// });
+/// # while !task.is_finished() {}
+/// ```
+#[derive(Resource, Clone)]
+pub struct TaskPool {
+    #[cfg(not(target_arch = "wasm32"))]
+    workers: Arc<Workers>,
+}
+
+impl std::fmt::Debug for TaskPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskPool")
+            .field("threads", &self.threads())
+            .finish()
+    }
+}
+
+impl Default for TaskPool {
+    /// Creates a task pool with a thread for each available core, up to 4 threads
+    fn default() -> Self {
+        let threads = std::thread::available_parallelism()
+            .map(|threads| threads.get())
+            .unwrap_or(1)
+            .min(4);
+
+        Self::new(threads)
+    }
+}
+
+impl TaskPool {
+    /// Creates a task pool that runs at most `threads` tasks at the same time
+    ///
+    /// # Panics
+    /// Panics if `threads` is zero
+    pub fn new(threads: usize) -> Self {
+        assert!(threads > 0, "A task pool needs at least one thread");
+
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            workers: Arc::new(Workers {
+                threads,
+                started: Mutex::new(false),
+                queue: Arc::default(),
+            }),
+        }
+    }
+
+    /// Returns the number of threads of the pool
+    pub fn threads(&self) -> usize {
+        #[cfg(not(target_arch = "wasm32"))]
+        return self.workers.threads;
+
+        #[cfg(target_arch = "wasm32")]
+        return 1;
+    }
+
+    /// Spawns a future that runs on one of the pool threads
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn<F: Future<Output = ()> + Send + 'static>(
+        &self,
+        priority: TaskPriority,
+        future: F,
+    ) -> Task {
+        self.spawn_cancelable(priority, |_| future)
+    }
+
+    /// Spawns the future returned by `create_future`, which receives the [Task] handle
+    /// so the future can check if it has been canceled while running
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_cancelable<F, Fut>(&self, priority: TaskPriority, create_future: F) -> Task
+    where
+        F: FnOnce(Task) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let task = Task::default();
+        let future = Box::pin(create_future(task.clone()));
+
+        self.workers.start();
+
+        let (queue, available) = &*self.workers.queue;
+        let mut queue = queue.lock().unwrap();
+        let sequence = queue.sequence;
+        queue.sequence += 1;
+        queue.tasks.push(QueuedTask {
+            priority,
+            sequence,
+            task: task.clone(),
+            future,
+        });
+        available.notify_one();
+
+        task
+    }
+
+    /// Spawns a future that runs on the browser event loop
+    #[cfg(target_arch = "wasm32")]
+    pub fn spawn<F: Future<Output = ()> + 'static>(
+        &self,
+        priority: TaskPriority,
+        future: F,
+    ) -> Task {
+        self.spawn_cancelable(priority, |_| future)
+    }
+
+    /// Spawns the future returned by `create_future`, which receives the [Task] handle
+    /// so the future can check if it has been canceled while running
+    #[cfg(target_arch = "wasm32")]
+    pub fn spawn_cancelable<F, Fut>(&self, _priority: TaskPriority, create_future: F) -> Task
+    where
+        F: FnOnce(Task) -> Fut,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let task = Task::default();
+        let future = create_future(task.clone());
+
+        let spawned_task = task.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            if !spawned_task.is_canceled() {
+                future.await;
+            }
+            spawned_task.finish();
+        });
+
+        task
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use std::{
+        collections::BTreeSet,
+        sync::{mpsc, Arc, Barrier},
+    };
+
+    use super::{TaskPool, TaskPriority};
+
+    #[test]
+    fn priority_and_cancel() {
+        let task_pool = TaskPool::new(1);
+        let (sender, receiver) = mpsc::channel();
+
+        // keeps the only thread busy while the other tasks are queued
+        let started = Arc::new(Barrier::new(2));
+        let barrier = Arc::new(Barrier::new(2));
+        let blocking_started = started.clone();
+        let blocking_barrier = barrier.clone();
+        task_pool.spawn(TaskPriority::High, async move {
+            blocking_started.wait();
+            blocking_barrier.wait();
+        });
+        started.wait();
+
+        for (name, priority) in [
+            ("low", TaskPriority::Low),
+            ("normal", TaskPriority::Normal),
+            ("high", TaskPriority::High),
+        ] {
+            let sender = sender.clone();
+            task_pool.spawn(priority, async move {
+                sender.send(name).unwrap();
+            });
+        }
+
+        let canceled_sender = sender.clone();
+        let canceled = task_pool.spawn(TaskPriority::High, async move {
+            canceled_sender.send("canceled").unwrap();
+        });
+        canceled.cancel();
+        drop(sender);
+
+        barrier.wait();
+
+        let order: Vec<&str> = receiver.iter().collect();
+        assert_eq!(order, vec!["high", "normal", "low"]);
+        assert!(canceled.is_finished());
+    }
+
+    #[test]
+    fn run_spawned_tasks_after_drop() {
+        let task_pool = TaskPool::new(1);
+        let (sender, receiver) = mpsc::channel();
+
+        for index in 0..10 {
+            let sender = sender.clone();
+            task_pool.spawn(TaskPriority::Normal, async move {
+                sender.send(index).unwrap();
+            });
+        }
+        drop(sender);
+        drop(task_pool);
+
+        assert_eq!(receiver.iter().count(), 10);
+    }
+
+    #[test]
+    fn bounded_threads() {
+        let task_pool = TaskPool::new(2);
+        let (sender, receiver) = mpsc::channel();
+
+        for _ in 0..20 {
+            let sender = sender.clone();
+            task_pool.spawn(TaskPriority::Normal, async move {
+                sender
+                    .send(std::thread::current().name().unwrap().to_owned())
+                    .unwrap();
+            });
+        }
+        drop(sender);
+
+        let threads: BTreeSet<String> = receiver.iter().collect();
+        assert!(threads.len() <= 2);
+        assert!(threads.iter().all(|name| name.starts_with("zengine-task-")));
+    }
+}
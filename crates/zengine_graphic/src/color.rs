@@ -27,6 +27,16 @@ impl Color {
         [self.r, self.g, self.b, self.a]
     }
 
+    /// Returns the color between this color and the other one at the given ratio
+    pub fn lerp(&self, other: Color, ratio: f32) -> Self {
+        Self {
+            r: self.r + (other.r - self.r) * ratio,
+            g: self.g + (other.g - self.g) * ratio,
+            b: self.b + (other.b - self.b) * ratio,
+            a: self.a + (other.a - self.a) * ratio,
+        }
+    }
+
     pub const WHITE: Self = Self {
         r: 1.,
         g: 1.,
@@ -1,7 +1,36 @@
 use image::{DynamicImage, GenericImageView};
-use zengine_asset::AssetLoader;
+use serde::Deserialize;
+use zengine_asset::{AssetLoader, LoaderError};
 use zengine_macro::Asset;
 
+/// The filter used to sample an [Image] when it's drawn scaled
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum ImageFilter {
+    /// Keeps the pixels sharp, good for pixel art
+    #[default]
+    Nearest,
+    /// Blends the nearby pixels
+    Linear,
+}
+
+/// Settings of the image loader
+///
+/// The settings of an image can be written in a `.meta` file next to it,
+/// like `player.png.meta` for `player.png`:
+/// ```ron
+/// (filter: Linear, premultiply_alpha: true)
+/// ```
+/// or passed to [AssetManager::load_with_settings](zengine_asset::AssetManager::load_with_settings)
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct ImageSettings {
+    pub filter: ImageFilter,
+    /// Multiplies the color channels by the alpha channel
+    pub premultiply_alpha: bool,
+    /// Treats the image data as linear instead of sRGB, like for a normal map
+    pub linear: bool,
+}
+
 /// [Asset](zengine_asset::Asset) that rappresent an Image
 #[derive(Asset, Default, Debug)]
 pub struct Image {
@@ -9,6 +38,9 @@ pub struct Image {
     pub height: u32,
 
     pub data: Vec<u8>,
+
+    pub filter: ImageFilter,
+    pub linear: bool,
 }
 
 impl Image {
@@ -17,6 +49,8 @@ impl Image {
             width,
             height,
             data,
+            filter: ImageFilter::default(),
+            linear: false,
         }
     }
 }
@@ -29,9 +63,13 @@ impl AssetLoader for ImageLoader {
         &["png", "jpg", "jpeg", "bmp"]
     }
 
-    fn load(&self, data: Vec<u8>, context: &mut zengine_asset::LoaderContext) {
-        let img =
-            image::load_from_memory(&data).unwrap_or_else(|e| panic!("Could not load image {}", e));
+    fn load(
+        &self,
+        data: Vec<u8>,
+        context: &mut zengine_asset::LoaderContext,
+    ) -> Result<(), LoaderError> {
+        let settings: ImageSettings = context.settings()?;
+        let img = image::load_from_memory(&data)?;
 
         let (width, height) = img.dimensions();
 
@@ -40,10 +78,24 @@ impl AssetLoader for ImageLoader {
             img => img.to_rgba8(),
         };
 
+        let mut data = img.into_raw();
+        if settings.premultiply_alpha {
+            for pixel in data.chunks_exact_mut(4) {
+                let alpha = pixel[3] as u16;
+                for channel in &mut pixel[..3] {
+                    *channel = ((*channel as u16 * alpha + 127) / 255) as u8;
+                }
+            }
+        }
+
         context.set_asset(Image {
             width,
             height,
-            data: img.into_raw(),
-        })
+            data,
+            filter: settings.filter,
+            linear: settings.linear,
+        });
+
+        Ok(())
     }
 }
@@ -1,6 +1,6 @@
 use sprite::{setup_sprite_render, sprite_render};
-use zengine_asset::AssetExtension;
-use zengine_engine::{Module, Stage};
+use zengine_asset::{AssetExtension, AssetModule};
+use zengine_engine::{Module, ModuleId, Stage};
 use zengine_macro::Resource;
 
 mod camera;
@@ -49,4 +49,11 @@ impl Module for GraphicModule {
             .add_system_into_stage(sprite_render, Stage::Render)
             .add_system_into_stage(present, Stage::PostRender);
     }
+
+    fn dependencies(&self) -> Vec<ModuleId> {
+        vec![
+            ModuleId::of::<AssetModule>(),
+            ModuleId::of::<zengine_window::WindowModule>(),
+        ]
+    }
 }
@@ -7,7 +7,7 @@ use rustc_hash::FxHashMap;
 use std::ops::{Deref, DerefMut};
 use wgpu::util::DeviceExt;
 use zengine_asset::{Assets, Handle};
-use zengine_core::Transform;
+use zengine_core::{Lens, Transform};
 use zengine_ecs::{
     query::{Query, QueryIter},
     system::{Commands, Local, Res, ResMut},
@@ -128,6 +128,19 @@ pub struct Sprite {
     pub texture: SpriteTexture,
 }
 
+/// A [Lens] that animates the [Sprite] color
+#[derive(Debug, Clone, Copy)]
+pub struct SpriteColorLens {
+    pub start: Color,
+    pub end: Color,
+}
+
+impl Lens<Sprite> for SpriteColorLens {
+    fn lerp(&mut self, target: &mut Sprite, ratio: f32) {
+        target.color = self.start.lerp(self.end, ratio);
+    }
+}
+
 #[doc(hidden)]
 #[derive(Resource, Default, Debug)]
 pub struct SpriteBuffer {
@@ -1,4 +1,4 @@
-use crate::{Device, Image, Queue, TextureBindGroupLayout};
+use crate::{Device, Image, ImageFilter, Queue, TextureBindGroupLayout};
 use glam::Vec2;
 use zengine_asset::{AssetEvent, Assets, Handle};
 use zengine_ecs::system::{EventStream, Res, ResMut};
@@ -55,7 +55,11 @@ impl Texture {
                 mip_level_count: 1,
                 sample_count: 1,
                 dimension: wgpu::TextureDimension::D2,
-                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                format: if image.linear {
+                    wgpu::TextureFormat::Rgba8Unorm
+                } else {
+                    wgpu::TextureFormat::Rgba8UnormSrgb
+                },
                 usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
                 label: Some("diffuse_texture"),
                 view_formats: &[],
@@ -78,12 +82,16 @@ impl Texture {
             );
 
             let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let filter = match image.filter {
+                ImageFilter::Nearest => wgpu::FilterMode::Nearest,
+                ImageFilter::Linear => wgpu::FilterMode::Linear,
+            };
             let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
                 address_mode_u: wgpu::AddressMode::ClampToEdge,
                 address_mode_v: wgpu::AddressMode::ClampToEdge,
                 address_mode_w: wgpu::AddressMode::ClampToEdge,
-                mag_filter: wgpu::FilterMode::Nearest,
-                min_filter: wgpu::FilterMode::Nearest,
+                mag_filter: filter,
+                min_filter: filter,
                 mipmap_filter: wgpu::FilterMode::Nearest,
                 ..Default::default()
             });
@@ -147,7 +155,7 @@ pub(crate) fn prepare_texture_asset(
     ) = (textures, images, device, queue, texture_bind_group_layout)
     {
         for e in events {
-            if let AssetEvent::Loaded(handle) = e {
+            if let AssetEvent::Loaded(handle) | AssetEvent::Modified(handle) = e {
                 let handle = Handle::weak(handle.get_id().clone_with_different_type::<Texture>());
                 if let Some(texture) = textures.get_mut(&handle) {
                     texture.convert_to_gpu_image(
@@ -1,4 +1,4 @@
-use crate::{Device, Image, Queue, Texture, TextureAssets, TextureBindGroupLayout};
+use crate::{Device, Image, ImageFilter, Queue, Texture, TextureAssets, TextureBindGroupLayout};
 use glam::Vec2;
 use rectangle_pack::{
     contains_smallest_box, pack_rects, volume_heuristic, GroupedRectsToPlace, PackedLocation,
@@ -30,6 +30,8 @@ pub(crate) struct ImageRect {
 pub struct TextureAtlas {
     width: u32,
     height: u32,
+    // strong handles to the source images, they are needed to pack again the atlas
+    // when one of them is modified
     images: FxHashMap<Handle<Image>, bool>,
     image_handles: FxHashMap<Handle<Image>, usize>,
     image_rects: Vec<ImageRect>,
@@ -57,6 +59,29 @@ impl TextureAtlas {
         !self.images.iter().any(|(_, flag)| !flag)
     }
 
+    /// Packs again a finalized atlas after one of its images has been modified.
+    /// Returns `false` if some of its images are not available anymore
+    fn rebuild_atlas(
+        &mut self,
+        images: &mut Assets<Image>,
+        textures: &mut Assets<Texture>,
+        device: &Device,
+        queue: &Queue,
+        texture_bind_group_layout: &TextureBindGroupLayout,
+    ) -> bool {
+        if !self
+            .images
+            .keys()
+            .all(|image_handle| images.contains(image_handle))
+        {
+            return false;
+        }
+
+        self.finalize_atlas(images, textures, device, queue, texture_bind_group_layout);
+
+        true
+    }
+
     fn finalize_atlas(
         &mut self,
         images: &mut Assets<Image>,
@@ -65,11 +90,44 @@ impl TextureAtlas {
         queue: &Queue,
         texture_bind_group_layout: &TextureBindGroupLayout,
     ) {
+        let final_image = self.pack_atlas(images);
+
+        let texture_handle = textures.create_texture(&images.add(final_image));
+        if let Some(texture) = textures.get_mut(&texture_handle) {
+            texture.convert_to_gpu_image(device, queue, texture_bind_group_layout, images);
+        }
+
+        self.texture = Some(texture_handle);
+    }
+
+    /// Returns the filter and the color space shared by all the images of the atlas,
+    /// the default ones are used when the images have different settings
+    fn image_settings(&self, images: &Assets<Image>) -> (ImageFilter, bool) {
+        let mut settings = self
+            .images
+            .keys()
+            .filter_map(|handle| images.get(handle))
+            .map(|image| (image.filter, image.linear));
+        let first = settings.next().unwrap_or_default();
+        if settings.all(|image_settings| image_settings == first) {
+            first
+        } else {
+            log::warn!(
+                "The images of a texture atlas have different filter or linear settings, \
+                the default ones are used"
+            );
+            Default::default()
+        }
+    }
+
+    /// Places the images in the atlas returning the atlas image
+    fn pack_atlas(&mut self, images: &Assets<Image>) -> Image {
         let mut width = 256;
         let mut height = 256;
 
         let mut placements = None;
         let mut final_image = Image::default();
+        let (filter, linear) = self.image_settings(images);
 
         let mut rects_to_place: GroupedRectsToPlace<Handle<Image>> = GroupedRectsToPlace::new();
         for (handle, image) in self
@@ -95,11 +153,15 @@ impl TextureAtlas {
                 &contains_smallest_box,
             ) {
                 Ok(placements) => {
-                    final_image = Image::new(
-                        width,
-                        height,
-                        vec![0; BYTE_PER_PIXEL * (width * height) as usize],
-                    );
+                    final_image = Image {
+                        filter,
+                        linear,
+                        ..Image::new(
+                            width,
+                            height,
+                            vec![0; BYTE_PER_PIXEL * (width * height) as usize],
+                        )
+                    };
 
                     Some(placements)
                 }
@@ -118,6 +180,7 @@ impl TextureAtlas {
         self.height = height;
 
         let placements = placements.unwrap();
+        self.image_handles.clear();
         self.image_rects = Vec::with_capacity(placements.packed_locations().len());
         for (image_handle, (_, location)) in placements.packed_locations().iter() {
             let image = images.get(image_handle).unwrap();
@@ -144,14 +207,7 @@ impl TextureAtlas {
             Self::copy_image_to_atlas(&mut final_image, image, location);
         }
 
-        let texture_handle = textures.create_texture(&images.add(final_image));
-        if let Some(texture) = textures.get_mut(&texture_handle) {
-            texture.convert_to_gpu_image(device, queue, texture_bind_group_layout, images);
-        }
-
-        self.images.clear();
-        self.images.shrink_to(0);
-        self.texture = Some(texture_handle);
+        final_image
     }
 
     fn copy_image_to_atlas(atlas_image: &mut Image, image: &Image, location: &PackedLocation) {
@@ -182,6 +238,10 @@ impl TextureAtlas {
 /// Add functionalities to create a [TextureAtlas] to the [`Assets<TextureAtlas>`] storage
 pub trait TextureAtlasAssets {
     /// Creates a [TextureAtlas] asset returning a strong [Handle] to it with the given Images handle
+    ///
+    /// The atlas keeps the images loaded, so it can be packed again when one of them is modified.
+    /// The atlas image uses the filter and linear settings shared by all the images,
+    /// or the default ones if the images have different settings
     fn create_texture_atlas(&mut self, images: &[&Handle<Image>]) -> Handle<TextureAtlas>;
 }
 
@@ -233,25 +293,133 @@ pub(crate) fn prepare_texture_atlas_asset(
         texture_bind_group_layout,
     ) {
         for e in events {
-            if let AssetEvent::Loaded(handle) = e {
-                let image_handle = Handle::weak(handle.get_id());
-                for (_, atlas) in textures_atlas
-                    .iter_mut()
-                    .filter(|(_, atlas)| !atlas.finalized())
-                {
-                    atlas.set_image_loaded(&image_handle);
-
-                    if atlas.all_images_loaded() {
-                        atlas.finalize_atlas(
+            match e {
+                AssetEvent::Loaded(handle) => {
+                    let image_handle = Handle::weak(handle.get_id());
+                    for (_, atlas) in textures_atlas
+                        .iter_mut()
+                        .filter(|(_, atlas)| !atlas.finalized())
+                    {
+                        atlas.set_image_loaded(&image_handle);
+
+                        if atlas.all_images_loaded() {
+                            atlas.finalize_atlas(
+                                &mut images,
+                                &mut textures,
+                                &device,
+                                &queue,
+                                &texture_bind_group_layout,
+                            );
+                        }
+                    }
+                }
+                AssetEvent::Modified(handle) => {
+                    let image_handle = Handle::weak(handle.get_id());
+                    for (_, atlas) in textures_atlas.iter_mut().filter(|(_, atlas)| {
+                        atlas.finalized() && atlas.images.contains_key(&image_handle)
+                    }) {
+                        // the atlas is packed again because the image size could be changed
+                        if !atlas.rebuild_atlas(
                             &mut images,
                             &mut textures,
                             &device,
                             &queue,
                             &texture_bind_group_layout,
-                        );
+                        ) {
+                            log::warn!(
+                                "A texture atlas can't be updated after an image has been modified \
+                                because some of its images have been removed"
+                            );
+                        }
                     }
                 }
+                _ => {}
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use zengine_asset::{AssetExtension, AssetModule, Assets, Handle};
+    use zengine_engine::Engine;
+
+    use super::{TextureAtlas, TextureAtlasAssets};
+    use crate::{Image, ImageFilter};
+
+    #[test]
+    fn keep_images_of_packed_atlas() {
+        let mut engine = Engine::default();
+        engine
+            .add_module(AssetModule::default())
+            .add_asset::<Image>()
+            .add_asset::<TextureAtlas>();
+        engine.startup();
+
+        let (atlas, image_handles): (Handle<TextureAtlas>, Vec<Handle<Image>>) = {
+            let mut images = engine.world.get_mut_resource::<Assets<Image>>().unwrap();
+            let mut atlases = engine
+                .world
+                .get_mut_resource::<Assets<TextureAtlas>>()
+                .unwrap();
+
+            let first = images.add(Image::new(2, 2, vec![255; 16]));
+            let second = images.add(Image::new(4, 1, vec![128; 16]));
+            let atlas = atlases.create_texture_atlas(&[&first, &second]);
+            atlases.get_mut(&atlas).unwrap().pack_atlas(&images);
+
+            // the caller drops its image handles after the atlas creation
+            (atlas, vec![first.clone_as_weak(), second.clone_as_weak()])
+        };
+
+        for _ in 0..3 {
+            engine.update();
+        }
+
+        let images = engine.world.get_resource::<Assets<Image>>().unwrap();
+        assert!(image_handles.iter().all(|handle| images.contains(handle)));
+
+        let mut atlases = engine
+            .world
+            .get_mut_resource::<Assets<TextureAtlas>>()
+            .unwrap();
+        let atlas = atlases.get_mut(&atlas).unwrap();
+        atlas.pack_atlas(&images);
+        assert_eq!(atlas.image_rects.len(), 2);
+    }
+
+    #[test]
+    fn keep_image_settings() {
+        let mut engine = Engine::default();
+        engine
+            .add_module(AssetModule::default())
+            .add_asset::<Image>()
+            .add_asset::<TextureAtlas>();
+        engine.startup();
+
+        let mut images = engine.world.get_mut_resource::<Assets<Image>>().unwrap();
+        let mut atlases = engine
+            .world
+            .get_mut_resource::<Assets<TextureAtlas>>()
+            .unwrap();
+
+        let linear_image = || Image {
+            filter: ImageFilter::Linear,
+            linear: true,
+            ..Image::new(1, 1, vec![0; 4])
+        };
+        let first = images.add(linear_image());
+        let second = images.add(linear_image());
+        let nearest = images.add(Image::new(1, 1, vec![0; 4]));
+
+        let atlas = atlases.create_texture_atlas(&[&first, &second]);
+        let atlas_image = atlases.get_mut(&atlas).unwrap().pack_atlas(&images);
+        assert_eq!(atlas_image.filter, ImageFilter::Linear);
+        assert!(atlas_image.linear);
+
+        let mixed = atlases.create_texture_atlas(&[&first, &nearest]);
+        let atlas_image = atlases.get_mut(&mixed).unwrap().pack_atlas(&images);
+        assert_eq!(atlas_image.filter, ImageFilter::Nearest);
+        assert!(!atlas_image.linear);
+    }
+}
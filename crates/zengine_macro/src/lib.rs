@@ -7,7 +7,8 @@ use syn::{
     parse::{Parse, ParseStream},
     parse_macro_input,
     token::Comma,
-    DeriveInput, Ident, Index, LitInt, Path, Result,
+    Data, DeriveInput, Error, Field, Fields, Ident, Index, Lit, LitInt, LitStr, Meta, NestedMeta,
+    Path, Result,
 };
 
 mod zengine_manifest;
@@ -106,6 +107,120 @@ pub fn input_type_macro_derive(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+enum AssetCollectionField {
+    Path(LitStr),
+    Folder(LitStr),
+    Default,
+}
+
+fn asset_collection_field(field: &Field) -> Result<AssetCollectionField> {
+    const USAGE: &str = "expected #[asset(path = \"...\")] or #[asset(folder = \"...\")]";
+
+    let mut kind = AssetCollectionField::Default;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("asset"))
+    {
+        let Meta::List(list) = attr.parse_meta()? else {
+            return Err(Error::new_spanned(attr, USAGE));
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(name_value)) => match name_value.lit {
+                    Lit::Str(value) if name_value.path.is_ident("path") => {
+                        kind = AssetCollectionField::Path(value)
+                    }
+                    Lit::Str(value) if name_value.path.is_ident("folder") => {
+                        kind = AssetCollectionField::Folder(value)
+                    }
+                    _ => return Err(Error::new_spanned(name_value, USAGE)),
+                },
+                nested => return Err(Error::new_spanned(nested, USAGE)),
+            }
+        }
+    }
+
+    Ok(kind)
+}
+
+/// Generates an impl of the `AssetCollection` trait.
+///
+/// Each field with an `#[asset(path = "...")]` attribute is loaded as a single asset,
+/// each field with an `#[asset(folder = "...")]` attribute is loaded as a `Vec` of assets,
+/// the load of the collection returns an error if the folder can't be listed.
+/// The other fields are created with their default value.
+#[proc_macro_derive(AssetCollection, attributes(asset))]
+pub fn asset_collection_macro_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let zengine_asset_path = ZENgineManifest::default().get_path("zengine_asset");
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Error::new_spanned(
+                    &input,
+                    "AssetCollection needs a struct with named fields",
+                )
+                .to_compile_error()
+                .into()
+            }
+        },
+        _ => {
+            return Error::new_spanned(&input, "AssetCollection can only be derived for a struct")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let mut field_inits = Vec::new();
+    let mut handle_ids = Vec::new();
+    for field in fields {
+        let field_name = field.ident.as_ref().unwrap();
+        match asset_collection_field(field) {
+            Ok(AssetCollectionField::Path(path)) => {
+                field_inits.push(quote! { #field_name: asset_manager.load(#path) });
+                handle_ids.push(quote! { ids.push(self.#field_name.get_id()); });
+            }
+            Ok(AssetCollectionField::Folder(folder)) => {
+                field_inits.push(quote! { #field_name: asset_manager.load_folder(#folder)? });
+                handle_ids.push(quote! {
+                    ids.extend(self.#field_name.iter().map(|handle| handle.get_id()));
+                });
+            }
+            Ok(AssetCollectionField::Default) => {
+                field_inits.push(quote! { #field_name: Default::default() });
+            }
+            Err(error) => return error.to_compile_error().into(),
+        }
+    }
+
+    let expanded = quote! {
+        impl #impl_generics #zengine_asset_path::AssetCollection for #name #ty_generics #where_clause {
+            fn load(
+                asset_manager: &mut #zengine_asset_path::AssetManager,
+            ) -> Result<Self, #zengine_asset_path::AssetError> {
+                Ok(Self {
+                    #(#field_inits,)*
+                })
+            }
+
+            fn handle_ids(&self) -> Vec<#zengine_asset_path::HandleId> {
+                #[allow(unused_mut)]
+                let mut ids = Vec::new();
+                #(#handle_ids)*
+                ids
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
 struct AllTuples {
     macro_ident: Ident,
     start: usize,
@@ -63,7 +63,8 @@ fn check_rectangle_and_circle(
 /// A simple collision system between [Shape2D]
 ///
 /// This system doesn't take in consideration the entity transform
-/// rotation for rectangular shape
+/// rotation for rectangular shape. Circles are scaled using the x scale
+/// of the entity transform
 pub fn collision_system(
     query: Query<(Entity, &Shape2D, &Transform)>,
     mut collision_event: EventPublisher<Collision>,
@@ -77,13 +78,13 @@ pub fn collision_system(
                     ShapeType::Circle { radius: a_radius },
                     ShapeType::Circle { radius: b_radius },
                 ) => {
-                    let diameter = *a_radius * 2.0 * a_transform.scale;
+                    let diameter = *a_radius * 2.0 * a_transform.scale.x;
                     let a_delta = Vec3::new(
                         diameter * -(-0.5 + a_shape.origin.x),
                         diameter * -(-0.5 + a_shape.origin.y),
                         0.0,
                     );
-                    let diameter = *b_radius * 2.0 * b_transform.scale;
+                    let diameter = *b_radius * 2.0 * b_transform.scale.x;
                     let b_delta = Vec3::new(
                         diameter * -(-0.5 + b_shape.origin.x),
                         diameter * -(-0.5 + b_shape.origin.y),
@@ -94,7 +95,7 @@ pub fn collision_system(
                         .abs();
 
                     let radius_lenghts =
-                        a_radius * a_transform.scale + b_radius * b_transform.scale;
+                        a_radius * a_transform.scale.x + b_radius * b_transform.scale.x;
                     distance < radius_lenghts
                 }
                 (
@@ -105,13 +106,13 @@ pub fn collision_system(
                     },
                 ) => check_rectangle_and_circle(
                     (
-                        &(b_width * b_transform.scale),
-                        &(b_height * b_transform.scale),
+                        &(b_width * b_transform.scale.x),
+                        &(b_height * b_transform.scale.y),
                         &b_transform.position,
                         &b_shape.origin,
                     ),
                     (
-                        &(a_radius * a_transform.scale),
+                        &(a_radius * a_transform.scale.x),
                         &a_transform.position,
                         &a_shape.origin,
                     ),
@@ -124,13 +125,13 @@ pub fn collision_system(
                     ShapeType::Circle { radius: b_radius },
                 ) => check_rectangle_and_circle(
                     (
-                        &(a_width * a_transform.scale),
-                        &(a_height * a_transform.scale),
+                        &(a_width * a_transform.scale.x),
+                        &(a_height * a_transform.scale.y),
                         &a_transform.position,
                         &a_shape.origin,
                     ),
                     (
-                        &(b_radius * b_transform.scale),
+                        &(b_radius * b_transform.scale.x),
                         &b_transform.position,
                         &b_shape.origin,
                     ),
@@ -145,10 +146,10 @@ pub fn collision_system(
                         height: b_height,
                     },
                 ) => {
-                    let left = a_width * a_shape.origin.x * a_transform.scale;
-                    let right = a_width * a_transform.scale - left;
-                    let bottom = a_height * a_shape.origin.y * a_transform.scale;
-                    let top = a_height * a_transform.scale - bottom;
+                    let left = a_width * a_shape.origin.x * a_transform.scale.x;
+                    let right = a_width * a_transform.scale.x - left;
+                    let bottom = a_height * a_shape.origin.y * a_transform.scale.y;
+                    let top = a_height * a_transform.scale.y - bottom;
 
                     let x = a_transform.position.x - left;
                     let y = a_transform.position.y - bottom;
@@ -160,10 +161,10 @@ pub fn collision_system(
                         point.x > x && point.x < extent_x && point.y > y && point.y < extent_y
                     };
 
-                    let left = b_width * b_shape.origin.x * b_transform.scale;
-                    let right = b_width * b_transform.scale - left;
-                    let bottom = b_height * b_shape.origin.y * b_transform.scale;
-                    let top = b_height * b_transform.scale - bottom;
+                    let left = b_width * b_shape.origin.x * b_transform.scale.x;
+                    let right = b_width * b_transform.scale.x - left;
+                    let bottom = b_height * b_shape.origin.y * b_transform.scale.y;
+                    let top = b_height * b_transform.scale.y - bottom;
 
                     point_in_shape(Vec3::new(
                         b_transform.position.x - left,
@@ -202,10 +203,10 @@ fn point_in_rectangle(
     shape_origin: Vec3,
     shape_transform: &Transform,
 ) -> bool {
-    let left = shape_width * shape_origin.x * shape_transform.scale;
-    let right = shape_width * shape_transform.scale - left;
-    let bottom = shape_height * shape_origin.y * shape_transform.scale;
-    let top = shape_height * shape_transform.scale - bottom;
+    let left = shape_width * shape_origin.x * shape_transform.scale.x;
+    let right = shape_width * shape_transform.scale.x - left;
+    let bottom = shape_height * shape_origin.y * shape_transform.scale.y;
+    let top = shape_height * shape_transform.scale.y - bottom;
 
     let x = shape_transform.position.x - left;
     let y = shape_transform.position.y - bottom;
@@ -223,7 +224,7 @@ pub fn point_in_shape(point: Vec3, shape: &Shape2D, shape_transform: &Transform)
             point_in_rectangle(point, width, height, shape.origin, shape_transform)
         }
         ShapeType::Circle { radius } => {
-            let diameter = radius * 2.0 * shape_transform.scale;
+            let diameter = radius * 2.0 * shape_transform.scale.x;
             let delta = Vec3::new(
                 diameter * -(-0.5 + shape.origin.x),
                 diameter * -(-0.5 + shape.origin.y),
@@ -232,7 +233,7 @@ pub fn point_in_shape(point: Vec3, shape: &Shape2D, shape_transform: &Transform)
 
             let distance = (shape_transform.position + delta).distance(point).abs();
 
-            let radius_lenghts = radius * shape_transform.scale;
+            let radius_lenghts = radius * shape_transform.scale.x;
             distance < radius_lenghts
         }
     }
@@ -1,6 +1,6 @@
 use std::ops::Deref;
 
-use zengine_asset::AssetLoader;
+use zengine_asset::{AssetLoader, LoaderError};
 use zengine_macro::Asset;
 
 #[derive(Asset, Debug)]
@@ -14,11 +14,11 @@ impl Deref for Font {
 }
 
 impl Font {
-    pub fn try_from_bytes(font_data: Vec<u8>) -> Self {
-        let font = fontdue::Font::from_bytes(font_data, fontdue::FontSettings::default())
-            .expect("Unable to load Font");
+    /// Creates a font from the raw data of a font file
+    pub fn try_from_bytes(font_data: Vec<u8>) -> Result<Self, &'static str> {
+        let font = fontdue::Font::from_bytes(font_data, fontdue::FontSettings::default())?;
 
-        Font(font)
+        Ok(Font(font))
     }
 }
 
@@ -30,9 +30,15 @@ impl AssetLoader for FontLoader {
         &["ttf", "otf"]
     }
 
-    fn load(&self, data: Vec<u8>, context: &mut zengine_asset::LoaderContext) {
-        let font = Font::try_from_bytes(data);
+    fn load(
+        &self,
+        data: Vec<u8>,
+        context: &mut zengine_asset::LoaderContext,
+    ) -> Result<(), LoaderError> {
+        let font = Font::try_from_bytes(data)?;
 
         context.set_asset(font);
+
+        Ok(())
     }
 }
@@ -4,15 +4,15 @@ use glam::{Mat4, Vec2};
 use text_atlas::TextAtlas;
 use text_render::TextRenderer;
 use wgpu::{LoadOp, Operations, RenderPassColorAttachment, RenderPassDescriptor};
-use zengine_asset::{AssetExtension, Assets};
+use zengine_asset::{AssetExtension, AssetModule, Assets};
 use zengine_core::Transform;
 use zengine_ecs::{
     query::{Query, QueryIter},
     system::{Commands, Local, Res, ResMut},
 };
-use zengine_engine::{Engine, Module, Stage};
+use zengine_engine::{Engine, Module, ModuleId, Stage};
 use zengine_graphic::{
-    CameraBuffer, Color, Device, Queue, RenderContextInstance, Surface, UsedCamera,
+    CameraBuffer, Color, Device, GraphicModule, Queue, RenderContextInstance, Surface, UsedCamera,
 };
 use zengine_window::WindowSpecs;
 
@@ -49,6 +49,13 @@ impl Module for TextModule {
             .add_startup_system(setup_text_render)
             .add_system_into_stage(text_render, Stage::Render);
     }
+
+    fn dependencies(&self) -> Vec<ModuleId> {
+        vec![
+            ModuleId::of::<AssetModule>(),
+            ModuleId::of::<GraphicModule>(),
+        ]
+    }
 }
 
 fn setup_text_render(
@@ -1,6 +1,7 @@
 use glam::Vec2;
 use typed_builder::TypedBuilder;
 use zengine_asset::Handle;
+use zengine_core::Lens;
 use zengine_graphic::Color;
 use zengine_macro::Component;
 
@@ -25,6 +26,36 @@ pub struct TextStyle {
     pub color: Color,
 }
 
+/// A [Lens] that animates the color of the [Text] style
+///
+/// The sections with their own style are not changed
+#[derive(Debug, Clone, Copy)]
+pub struct TextColorLens {
+    pub start: Color,
+    pub end: Color,
+}
+
+impl Lens<Text> for TextColorLens {
+    fn lerp(&mut self, target: &mut Text, ratio: f32) {
+        target.style.color = self.start.lerp(self.end, ratio);
+    }
+}
+
+/// A [Lens] that animates the font size of the [Text] style
+///
+/// The sections with their own style are not changed
+#[derive(Debug, Clone, Copy)]
+pub struct TextFontSizeLens {
+    pub start: f32,
+    pub end: f32,
+}
+
+impl Lens<Text> for TextFontSizeLens {
+    fn lerp(&mut self, target: &mut Text, ratio: f32) {
+        target.style.font_size = self.start + (self.end - self.start) * ratio;
+    }
+}
+
 #[derive(TypedBuilder, Debug)]
 pub struct TextSection {
     pub value: String,
@@ -8,7 +8,7 @@ use winit::{
     event_loop::ActiveEventLoop,
     window::{Fullscreen, WindowId},
 };
-use zengine_engine::{Engine, EngineEvent};
+use zengine_engine::{AppExit, Engine, EngineEvent};
 use zengine_input::{Axis, Input, InputEvent};
 
 use crate::{RunnerState, Window, WindowConfig, WindowSpecs};
@@ -16,6 +16,7 @@ use crate::{RunnerState, Window, WindowConfig, WindowSpecs};
 pub(crate) struct Application {
     engine: Engine,
     state: RunnerState,
+    exit: Option<AppExit>,
 }
 
 impl Application {
@@ -23,9 +24,14 @@ impl Application {
         Self {
             engine,
             state: RunnerState::Initializing,
+            exit: None,
         }
     }
 
+    pub fn exit_status(&self) -> AppExit {
+        self.exit.unwrap_or(AppExit::Success)
+    }
+
     fn create_window(&mut self, event_loop: &ActiveEventLoop) {
         let (window, window_spec) = {
             let window_config = self.engine.world.get_resource::<WindowConfig>().unwrap();
@@ -145,6 +151,7 @@ impl ApplicationHandler for Application {
     ) {
         match event {
             WindowEvent::CloseRequested => {
+                self.exit = Some(AppExit::Success);
                 event_loop.exit();
             }
             WindowEvent::Resized(size) => {
@@ -256,13 +263,8 @@ impl ApplicationHandler for Application {
                     self.state = RunnerState::Suspended;
                 }
 
-                if self
-                    .engine
-                    .world
-                    .get_event_handler::<EngineEvent>()
-                    .and_then(|event| event.read_last().map(|e| e == &EngineEvent::Quit))
-                    .unwrap_or(false)
-                {
+                if let Some(exit) = self.engine.exit_requested() {
+                    self.exit = Some(exit);
                     event_loop.exit();
                 } else if let Some(window) = self.engine.world.get_unsendable_resource::<Window>() {
                     window.internal.request_redraw();
@@ -271,4 +273,10 @@ impl ApplicationHandler for Application {
             _ => (),
         }
     }
+
+    fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+        info!("Shutdown Engine");
+
+        self.engine.shutdown();
+    }
 }
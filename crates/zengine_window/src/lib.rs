@@ -2,7 +2,7 @@ use application::Application;
 use glam::UVec2;
 use std::sync::Arc;
 use winit::event_loop::ControlFlow;
-use zengine_engine::{Engine, Module};
+use zengine_engine::{AppExit, Engine, Module};
 use zengine_macro::{Resource, UnsendableResource};
 
 #[cfg(target_os = "android")]
@@ -75,11 +75,16 @@ impl Module for WindowModule {
     }
 }
 
-fn runner(engine: Engine) {
+fn runner(engine: Engine) -> AppExit {
     let event_loop = winit::event_loop::EventLoop::new().unwrap();
 
     event_loop.set_control_flow(ControlFlow::Poll);
 
     let mut app = Application::new(engine);
-    let _ = event_loop.run_app(&mut app);
+    if let Err(error) = event_loop.run_app(&mut app) {
+        log::error!("Event loop error: {}", error);
+        return AppExit::error();
+    }
+
+    app.exit_status()
 }
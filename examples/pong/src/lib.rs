@@ -2,10 +2,10 @@ use serde::Deserialize;
 use zengine::{
     asset::{AssetManager, AssetModule, Assets, Handle},
     audio::{Audio, AudioDevice, AudioInstance, AudioModule, AudioSettings},
-    core::{Time, TimeModule, Transform},
+    core::{FixedTime, TimeModule, Transform},
     ecs::{
         query::{Query, QueryIter, QueryIterMut},
-        system::{Commands, EventPublisher, EventStream, Local, Res, ResMut},
+        system::{Commands, EventPublisher, EventStream, Local, ParamSet, Res, ResMut},
         Entity,
     },
     gamepad::GamepadModule,
@@ -21,7 +21,7 @@ use zengine::{
     math::{Vec2, Vec3},
     physics::{collision_system, Collision, Shape2D, ShapeType},
     window::{WindowConfig, WindowModule, WindowSpecs},
-    Component, Engine, InputType, Resource,
+    Component, Engine, InputType, Resource, Stage,
 };
 
 static PAD_FORCE: f32 = 2000.0;
@@ -157,8 +157,8 @@ pub fn main() {
         .add_system(collision_system)
         .add_system(ai_pad_control)
         .add_system(player_pad_control)
-        .add_system(pad_movement)
-        .add_system(ball_movement)
+        .add_system_into_stage(pad_movement, Stage::FixedUpdate)
+        .add_system_into_stage(ball_movement, Stage::FixedUpdate)
         .add_system(collision_response)
         .run();
 }
@@ -226,7 +226,7 @@ fn setup(
         Camera {
             mode: CameraMode::Mode2D(Vec2::new(camera_width, camera_height)),
         },
-        Transform::new(Vec3::new(0.0, 0.0, -50.0), Vec3::new(0.0, 0.0, 0.0), 1.0),
+        Transform::from_position(Vec3::new(0.0, 0.0, -50.0)),
     ));
 
     commands.create_resource(ActiveCamera { entity: camera });
@@ -238,7 +238,7 @@ fn setup(
             color: Color::WHITE,
             texture: SpriteTexture::Simple(bg),
         },
-        Transform::new(Vec3::new(0.0, 0.0, 3.0), Vec3::new(0.0, 0.0, 0.0), 1.0),
+        Transform::from_position(Vec3::new(0.0, 0.0, 3.0)),
     ));
 
     commands.create_resource(Dimensions {
@@ -258,15 +258,11 @@ fn setup(
                 target_image: Some(board),
             },
         },
-        Transform::new(Vec3::new(0.0, 0.0, 2.0), Vec3::new(0.0, 0.0, 0.0), 1.0),
+        Transform::from_position(Vec3::new(0.0, 0.0, 2.0)),
     ));
 
     let sx = commands.spawn((
-        Transform::new(
-            Vec3::new(-board_width / 2., 0.0, 0.0),
-            Vec3::new(0.0, 0.0, 0.0),
-            1.0,
-        ),
+        Transform::from_position(Vec3::new(-board_width / 2., 0.0, 0.0)),
         Shape2D {
             origin: Vec3::new(1.0, 0.5, 0.0),
             shape_type: ShapeType::Rectangle {
@@ -276,11 +272,7 @@ fn setup(
         },
     ));
     let dx = commands.spawn((
-        Transform::new(
-            Vec3::new(board_width / 2., 0.0, 0.0),
-            Vec3::new(0.0, 0.0, 0.0),
-            1.0,
-        ),
+        Transform::from_position(Vec3::new(board_width / 2., 0.0, 0.0)),
         Shape2D {
             origin: Vec3::new(0.0, 0.5, 0.0),
             shape_type: ShapeType::Rectangle {
@@ -291,11 +283,7 @@ fn setup(
     ));
 
     let top = commands.spawn((
-        Transform::new(
-            Vec3::new(0.0, board_height / 2., 0.0),
-            Vec3::new(0.0, 0.0, 0.0),
-            1.0,
-        ),
+        Transform::from_position(Vec3::new(0.0, board_height / 2., 0.0)),
         Shape2D {
             origin: Vec3::new(0.5, 0.0, 0.0),
             shape_type: ShapeType::Rectangle {
@@ -306,11 +294,7 @@ fn setup(
     ));
 
     let bottom = commands.spawn((
-        Transform::new(
-            Vec3::new(0.0, -board_height / 2., 0.0),
-            Vec3::new(0.0, 0.0, 0.0),
-            1.0,
-        ),
+        Transform::from_position(Vec3::new(0.0, -board_height / 2., 0.0)),
         Shape2D {
             origin: Vec3::new(0.5, 1.0, 0.0),
             shape_type: ShapeType::Rectangle {
@@ -337,11 +321,11 @@ fn setup(
                 target_image: Some(pad_image.clone_as_weak()),
             },
         },
-        Transform::new(
-            Vec3::new(0.0, -(board_height / 2.) + 20.0 + pad_half_height, 1.0),
-            Vec3::ZERO,
+        Transform::from_position(Vec3::new(
+            0.0,
+            -(board_height / 2.) + 20.0 + pad_half_height,
             1.0,
-        ),
+        )),
         Shape2D {
             origin: Vec3::new(0.5, 0.5, 0.0),
             shape_type: ShapeType::Rectangle {
@@ -363,11 +347,12 @@ fn setup(
                 target_image: Some(pad_image.clone_as_weak()),
             },
         },
-        Transform::new(
-            Vec3::new(0.0, board_height / 2. - 20.0 - pad_half_height, 1.0),
-            Vec3::new(0., 0., 180.),
+        Transform::from_position(Vec3::new(
+            0.0,
+            board_height / 2. - 20.0 - pad_half_height,
             1.0,
-        ),
+        ))
+        .with_euler_degrees(Vec3::new(0., 0., 180.)),
         Shape2D {
             origin: Vec3::new(0.5, 0.5, 0.0),
             shape_type: ShapeType::Rectangle {
@@ -389,7 +374,7 @@ fn setup(
                 target_image: Some(ball.clone_as_weak()),
             },
         },
-        Transform::new(Vec3::new(0.0, 0.0, 1.0), Vec3::ZERO, 1.0),
+        Transform::from_position(Vec3::new(0.0, 0.0, 1.0)),
         Shape2D {
             origin: Vec3::new(0.5, 0.5, 0.0),
             shape_type: ShapeType::Circle {
@@ -427,7 +412,7 @@ fn ai_pad_control(
     if let Some(ball_transform) = ball_query
         .iter()
         .next()
-        .map(|(_, ball_transform)| ball_transform.clone())
+        .map(|(_, ball_transform)| *ball_transform)
     {
         for (_, pad, transform) in ai_query.iter_mut() {
             pad.cur_acc = if ball_transform.position.x > transform.position.x {
@@ -443,20 +428,20 @@ fn ai_pad_control(
 fn pad_movement(
     mut query: Query<(&mut Transform, &mut Pad)>,
     game_settings: Res<GameSettings>,
-    time: Res<Time>,
+    fixed_time: Res<FixedTime>,
 ) {
     for (transform, pad) in query.iter_mut() {
         let drag_acc = -game_settings.drag_constant * pad.velocity / pad.mass;
-        pad.velocity +=
-            pad.cur_acc * time.delta().as_secs_f32() + drag_acc * time.delta().as_secs_f32();
-        transform.position.x += pad.velocity * time.delta().as_secs_f32();
+        pad.velocity += pad.cur_acc * fixed_time.delta().as_secs_f32()
+            + drag_acc * fixed_time.delta().as_secs_f32();
+        transform.position.x += pad.velocity * fixed_time.delta().as_secs_f32();
     }
 
     for (transform, pad) in query.iter_mut() {
         let drag_acc = -game_settings.drag_constant * pad.velocity / pad.mass;
-        pad.velocity +=
-            pad.cur_acc * time.delta().as_secs_f32() + drag_acc * time.delta().as_secs_f32();
-        transform.position.x += pad.velocity * time.delta().as_secs_f32();
+        pad.velocity += pad.cur_acc * fixed_time.delta().as_secs_f32()
+            + drag_acc * fixed_time.delta().as_secs_f32();
+        transform.position.x += pad.velocity * fixed_time.delta().as_secs_f32();
     }
 }
 
@@ -468,7 +453,7 @@ struct BallMovement {
 
 fn ball_movement(
     mut query: Query<(&mut Transform, &mut Ball)>,
-    time: Res<Time>,
+    fixed_time: Res<FixedTime>,
     game_events: EventStream<GameEvent>,
     ball_movement: Local<BallMovement>,
 ) {
@@ -477,11 +462,11 @@ fn ball_movement(
     }
     if ball_movement.launched {
         for (transform, ball) in query.iter_mut() {
-            transform.position.x += ball.vel.x * time.delta().as_secs_f32();
-            transform.position.y += ball.vel.y * time.delta().as_secs_f32();
+            transform.position.x += ball.vel.x * fixed_time.delta().as_secs_f32();
+            transform.position.y += ball.vel.y * fixed_time.delta().as_secs_f32();
         }
     } else {
-        ball_movement.respawn += time.delta().as_secs_f32();
+        ball_movement.respawn += fixed_time.delta().as_secs_f32();
         if ball_movement.respawn > 5.0 {
             ball_movement.launched = true;
             ball_movement.respawn = 0.0;
@@ -510,8 +495,10 @@ fn initial_ball_movement() -> Vec2 {
 
 #[allow(clippy::too_many_arguments)]
 fn collision_response(
-    mut query_pad: Query<(Entity, &mut Transform, &mut Pad)>,
-    mut query_ball: Query<(Entity, &mut Transform, &mut Ball)>,
+    mut queries: ParamSet<(
+        Query<(Entity, &mut Transform, &mut Pad)>,
+        Query<(Entity, &mut Transform, &mut Ball)>,
+    )>,
     collision_event: EventStream<Collision>,
     field_border: Option<Res<FieldBorder>>,
     mut game_event: EventPublisher<GameEvent>,
@@ -522,8 +509,8 @@ fn collision_response(
 ) {
     fn get_collision_type(
         collision: &Collision,
-        query_pad: &Query<(Entity, &mut Transform, &mut Pad)>,
-        query_ball: &Query<(Entity, &mut Transform, &mut Ball)>,
+        pads: &[Entity],
+        balls: &[Entity],
         field_border: &FieldBorder,
     ) -> Option<CollisionType> {
         let get_field_border = |entity: Entity| -> Option<Side> {
@@ -540,73 +527,49 @@ fn collision_response(
             None
         };
 
-        if query_pad
-            .iter()
-            .any(|(entity, _, _)| entity == &collision.entity_a)
-        {
+        if pads.contains(&collision.entity_a) {
             if let Some(border) = get_field_border(collision.entity_b) {
                 return Some(CollisionType::PadBorder {
                     pad: collision.entity_a,
                     border,
                 });
-            } else if query_ball
-                .iter()
-                .any(|(entity, _, _)| entity == &collision.entity_b)
-            {
+            } else if balls.contains(&collision.entity_b) {
                 return Some(CollisionType::BallPad {
                     pad: collision.entity_a,
                     ball: collision.entity_b,
                 });
             }
-        } else if query_pad
-            .iter()
-            .any(|(entity, _, _)| entity == &collision.entity_b)
-        {
+        } else if pads.contains(&collision.entity_b) {
             if let Some(border) = get_field_border(collision.entity_a) {
                 return Some(CollisionType::PadBorder {
                     pad: collision.entity_b,
                     border,
                 });
-            } else if query_ball
-                .iter()
-                .any(|(entity, _, _)| entity == &collision.entity_a)
-            {
+            } else if balls.contains(&collision.entity_a) {
                 return Some(CollisionType::BallPad {
                     pad: collision.entity_b,
                     ball: collision.entity_a,
                 });
             }
-        } else if query_ball
-            .iter()
-            .any(|(entity, _, _)| entity == &collision.entity_a)
-        {
+        } else if balls.contains(&collision.entity_a) {
             if let Some(border) = get_field_border(collision.entity_b) {
                 return Some(CollisionType::BallBorder {
                     ball: collision.entity_a,
                     border,
                 });
-            } else if query_pad
-                .iter()
-                .any(|(entity, _, _)| entity == &collision.entity_b)
-            {
+            } else if pads.contains(&collision.entity_b) {
                 return Some(CollisionType::BallPad {
                     pad: collision.entity_b,
                     ball: collision.entity_a,
                 });
             }
-        } else if query_ball
-            .iter()
-            .any(|(entity, _, _)| entity == &collision.entity_b)
-        {
+        } else if balls.contains(&collision.entity_b) {
             if let Some(border) = get_field_border(collision.entity_a) {
                 return Some(CollisionType::BallBorder {
                     ball: collision.entity_b,
                     border,
                 });
-            } else if query_pad
-                .iter()
-                .any(|(entity, _, _)| entity == &collision.entity_a)
-            {
+            } else if pads.contains(&collision.entity_a) {
                 return Some(CollisionType::BallPad {
                     pad: collision.entity_a,
                     ball: collision.entity_b,
@@ -617,14 +580,17 @@ fn collision_response(
         None
     }
 
+    let pads: Vec<Entity> = queries.p0().iter().map(|(e, _, _)| *e).collect();
+    let balls: Vec<Entity> = queries.p1().iter().map(|(e, _, _)| *e).collect();
+
     if let Some(field_border) = field_border {
         for c in collision_event.read() {
-            match get_collision_type(c, &query_pad, &query_ball, &field_border) {
+            match get_collision_type(c, &pads, &balls, &field_border) {
                 Some(CollisionType::PadBorder {
                     pad: pad_entity,
                     border: Side::Sx(_),
                 }) => {
-                    if let Some((pad, transform)) = query_pad.iter_mut().find_map(|(e, t, p)| {
+                    if let Some((pad, transform)) = queries.p0().iter_mut().find_map(|(e, t, p)| {
                         if e == &pad_entity {
                             Some((p, t))
                         } else {
@@ -640,7 +606,7 @@ fn collision_response(
                     pad: pad_entity,
                     border: Side::Dx(_),
                 }) => {
-                    if let Some((pad, transform)) = query_pad.iter_mut().find_map(|(e, t, p)| {
+                    if let Some((pad, transform)) = queries.p0().iter_mut().find_map(|(e, t, p)| {
                         if e == &pad_entity {
                             Some((p, t))
                         } else {
@@ -660,13 +626,15 @@ fn collision_response(
                     ball: ball_entity,
                     border: Side::Dx(border_entity),
                 }) => {
-                    if let Some((ball, transform)) = query_ball.iter_mut().find_map(|(e, t, b)| {
-                        if e == &ball_entity {
-                            Some((b, t))
-                        } else {
-                            None
-                        }
-                    }) {
+                    if let Some((ball, transform)) =
+                        queries.p1().iter_mut().find_map(|(e, t, b)| {
+                            if e == &ball_entity {
+                                Some((b, t))
+                            } else {
+                                None
+                            }
+                        })
+                    {
                         ball.vel = Vec2::new(-ball.vel.x, ball.vel.y);
                         transform.position.x = if border_entity == field_border.sx {
                             (-dimensions.board_width / 2.) + dimensions.ball_radius + 0.1
@@ -685,13 +653,15 @@ fn collision_response(
                     ball: ball_entity,
                     border: Side::Top(_),
                 }) => {
-                    if let Some((ball, transform)) = query_ball.iter_mut().find_map(|(e, t, b)| {
-                        if e == &ball_entity {
-                            Some((b, t))
-                        } else {
-                            None
-                        }
-                    }) {
+                    if let Some((ball, transform)) =
+                        queries.p1().iter_mut().find_map(|(e, t, b)| {
+                            if e == &ball_entity {
+                                Some((b, t))
+                            } else {
+                                None
+                            }
+                        })
+                    {
                         transform.position.x = 0.0;
                         transform.position.y = 0.0;
 
@@ -708,15 +678,16 @@ fn collision_response(
                     pad: pad_entity,
                     ball: ball_entity,
                 }) => {
-                    if let Some(pad_transform) = query_pad.iter().find_map(|(e, t, _)| {
+                    let pad_transform = queries.p0().iter().find_map(|(e, t, _)| {
                         if e == &pad_entity {
-                            Some(t.clone())
+                            Some(*t)
                         } else {
                             None
                         }
-                    }) {
+                    });
+                    if let Some(pad_transform) = pad_transform {
                         if let Some((ball_transform, ball)) =
-                            query_ball.iter_mut().find_map(|(e, t, b)| {
+                            queries.p1().iter_mut().find_map(|(e, t, b)| {
                                 if e == &ball_entity {
                                     Some((t, b))
                                 } else {
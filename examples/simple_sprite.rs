@@ -48,7 +48,7 @@ fn setup(
         Camera {
             mode: CameraMode::Mode2D(Vec2::new(3.55, 2.0)),
         },
-        Transform::new(Vec3::new(0.0, 0.0, -1.0), Vec3::new(0.0, 0.0, 0.0), 1.0),
+        Transform::from_position(Vec3::new(0.0, 0.0, -1.0)),
     ));
 
     commands.spawn((
@@ -58,6 +58,6 @@ fn setup(
             color: Color::WHITE,
             texture: SpriteTexture::Simple(texture),
         },
-        Transform::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 0.0), 1.0),
+        Transform::from_position(Vec3::new(0.0, 0.0, 0.0)),
     ));
 }
@@ -1,13 +1,15 @@
 use zengine::{
-    asset::{AssetManager, AssetModule},
+    asset::AssetManager,
+    audio::AudioModule,
     core::Transform,
     ecs::system::{Commands, ResMut},
-    graphic::{Background, Camera, CameraMode, Color, GraphicModule},
+    gamepad::GamepadModule,
+    graphic::{Background, Camera, CameraMode, Color},
     log::Level,
     math::{vec2, Vec2, Vec3},
-    text::{Text, TextModule, TextSection, TextStyle},
+    text::{Text, TextSection, TextStyle},
     window::{WindowConfig, WindowModule},
-    Engine,
+    DefaultModules, Engine, ModuleGroup,
 };
 use zengine_asset::Handle;
 use zengine_text::Font;
@@ -17,16 +19,19 @@ fn main() {
     Engine::init_logger(Level::Info);
 
     Engine::default()
-        .add_module(WindowModule(WindowConfig {
-            title: "Simple Text".to_owned(),
-            width: 1280,
-            height: 720,
-            fullscreen: false,
-            vsync: false,
-        }))
-        .add_module(AssetModule::new("assets"))
-        .add_module(GraphicModule::default())
-        .add_module(TextModule::default())
+        .add_modules(
+            DefaultModules
+                .build()
+                .set(WindowModule(WindowConfig {
+                    title: "Simple Text".to_owned(),
+                    width: 1280,
+                    height: 720,
+                    fullscreen: false,
+                    vsync: false,
+                }))
+                .disable::<AudioModule>()
+                .disable::<GamepadModule>(),
+        )
         .add_startup_system(setup)
         .run();
 }
@@ -44,7 +49,7 @@ fn setup(mut commands: Commands, mut asset_manager: ResMut<AssetManager>) {
         Camera {
             mode: CameraMode::Mode2D(Vec2::new(camera_width, camera_width / 1.777)),
         },
-        Transform::new(Vec3::new(0.0, 0.0, -1.0), Vec3::new(0.0, 0.0, 0.0), 1.0),
+        Transform::from_position(Vec3::new(0.0, 0.0, -1.0)),
     ));
 
     commands.spawn((
@@ -60,6 +65,6 @@ fn setup(mut commands: Commands, mut asset_manager: ResMut<AssetManager>) {
             )
             .bounds(vec2(4., 1.))
             .build(),
-        Transform::new(Vec3::new(-1.3, 0., 0.0), Vec3::new(0.0, 0.0, 0.0), 1.0),
+        Transform::from_position(Vec3::new(-1.3, 0., 0.0)),
     ));
 }
@@ -87,5 +87,53 @@ pub mod text {
     pub use zengine_text::*;
 }
 
+/// A [ModuleGroup] with the modules commonly used by a game:
+/// window, assets, time, tweening, graphic, text, audio and gamepad support
+///
+/// The tween module plays the [Animator](core::Animator)s of
+/// [Transform](core::Transform), [Sprite](graphic::Sprite) and [Text](text::Text)
+///
+/// Each module can be replaced or disabled before adding the group to the engine
+///
+/// # Example
+/// ```no_run
+/// use zengine::{
+///     audio::AudioModule,
+///     window::{WindowConfig, WindowModule},
+///     DefaultModules, Engine, ModuleGroup,
+/// };
+///
+/// Engine::default()
+///     .add_modules(
+///         DefaultModules
+///             .build()
+///             .set(WindowModule(WindowConfig {
+///                 title: "My Game".to_owned(),
+///                 ..Default::default()
+///             }))
+///             .disable::<AudioModule>(),
+///     )
+///     .run();
+/// ```
+pub struct DefaultModules;
+
+impl ModuleGroup for DefaultModules {
+    fn build(self) -> ModuleGroupBuilder {
+        ModuleGroupBuilder::default()
+            .add_module(window::WindowModule::default())
+            .add_module(asset::AssetModule::new("assets"))
+            .add_module(core::TimeModule(None))
+            .add_module(
+                core::TweenModule::default()
+                    .with_component::<graphic::Sprite>()
+                    .with_component::<text::Text>(),
+            )
+            .add_module(graphic::GraphicModule)
+            .add_module(text::TextModule)
+            .add_module(audio::AudioModule)
+            .add_module(gamepad::GamepadModule)
+    }
+}
+
 extern crate zengine_macro;
 pub use zengine_macro::*;